@@ -0,0 +1,84 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::collections::{HashMap, HashSet};
+use crate::prelude::*;
+use crate::Graph;
+
+impl<T: FromStr + Display> Graph<T> {
+    /// Assign colors `0, 1, 2, ...` to vertices, so that vertices connected by edge
+    /// in any direction have different colors.
+    ///
+    /// Greedy heuristic is used: vertices are colored in order of descending undirected degree
+    /// (ties broken by ascending id), each getting smallest color not used by its colored neighbours.
+    /// Number of used colors is an upper bound of chromatic number, not necessarily the optimum.
+    /// Self-loops are ignored.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(3, 1).is_ok());
+    /// assert!(graph.add_edge(4, 1).is_ok());
+    /// let colors = graph.greedy_coloring();
+    /// assert_eq!(colors.len(), 4);
+    /// assert_ne!(colors[&1], colors[&2]);
+    /// assert_ne!(colors[&2], colors[&3]);
+    /// assert_ne!(colors[&3], colors[&1]);
+    /// assert_ne!(colors[&4], colors[&1]);
+    /// ```
+    pub fn greedy_coloring(&self) -> HashMap<usize, usize> {
+        let mut adjacent: HashMap<usize, HashSet<usize>> = self
+            .get_vertices_ids()
+            .into_iter()
+            .map(|v| (v, HashSet::new()))
+            .collect();
+        for (u, v) in self.undirected_edges() {
+            if u != v {
+                adjacent.get_mut(&u).unwrap().insert(v);
+                adjacent.get_mut(&v).unwrap().insert(u);
+            }
+        }
+        let mut order: Vec<_> = adjacent.keys().copied().collect();
+        order.sort_unstable_by_key(|v| (core::cmp::Reverse(adjacent[v].len()), *v));
+
+        let mut colors = HashMap::new();
+        for v in order {
+            let used: HashSet<usize> = adjacent[&v]
+                .iter()
+                .filter_map(|u| colors.get(u).copied())
+                .collect();
+            let color = (0..).find(|c| !used.contains(c)).unwrap();
+            colors.insert(v, color);
+        }
+        colors
+    }
+
+    /// Get number of colors used by [`Graph::greedy_coloring`].
+    ///
+    /// Upper bound of chromatic number of graph viewed as undirected, `0` for empty graph.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// assert_eq!(graph.chromatic_number_upper_bound(), 0);
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert_eq!(graph.chromatic_number_upper_bound(), 1);
+    /// for i in 1..=4 {
+    ///     assert!(graph.add_edge(i, i % 4 + 1).is_ok());
+    /// }
+    /// assert_eq!(graph.chromatic_number_upper_bound(), 2);
+    /// ```
+    pub fn chromatic_number_upper_bound(&self) -> usize {
+        self.greedy_coloring()
+            .into_values()
+            .max()
+            .map_or(0, |max_color| max_color + 1)
+    }
+}
@@ -0,0 +1,419 @@
+use alloc::collections::BinaryHeap;
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::collections::{HashMap, HashSet};
+use crate::prelude::*;
+use crate::visitors::topological_sort::TopologicalSort;
+use crate::{CycleError, Graph, GraphVertex};
+
+/// Vertex in heap of ready vertices, the least vertex by comparator is on top.
+struct ReadyVertex<'a, F> {
+    vertex: usize,
+    cmp: &'a F,
+}
+
+impl<F: Fn(usize, usize) -> Ordering> PartialEq for ReadyVertex<'_, F> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.cmp)(self.vertex, other.vertex) == Ordering::Equal
+    }
+}
+
+impl<F: Fn(usize, usize) -> Ordering> Eq for ReadyVertex<'_, F> {}
+
+impl<F: Fn(usize, usize) -> Ordering> PartialOrd for ReadyVertex<'_, F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: Fn(usize, usize) -> Ordering> Ord for ReadyVertex<'_, F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.cmp)(other.vertex, self.vertex)
+    }
+}
+
+impl<T: FromStr + Display> Graph<T> {
+    /// Get vertices in topological order: for each edge `u -> v`, `u` goes before `v`.
+    /// The same order is used by [`VisitOrder::TopologicalSort`](crate::VisitOrder::TopologicalSort).
+    ///
+    /// Note: works on acyclic graphs. If cycle exists, order is undefined.
+    ///
+    /// Order is stable across runs: dfs starts from vertices in ascending order of ids
+    /// and moves to neighbours in ascending order too.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=5 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(2, 4).is_ok());
+    /// assert!(graph.add_edge(2, 5).is_ok());
+    /// assert_eq!(graph.topological_order(), vec![2, 5, 4, 1, 3]);
+    /// ```
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// // Two roots 1 and 2, diamond 1 -> {3, 4} -> 5 and separate chain 2 -> 6 -> 5.
+    /// let mut graph = Graph::new();
+    /// for i in 1..=7 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// let edges = [(1, 3), (1, 4), (3, 5), (4, 5), (2, 6), (6, 5)];
+    /// for (u, v) in edges {
+    ///     assert!(graph.add_edge(u, v).is_ok());
+    /// }
+    /// for _ in 0..10 {
+    ///     let order = graph.topological_order();
+    ///     assert_eq!(order.len(), 7);
+    ///     let position = |v| order.iter().position(|&u| u == v).unwrap();
+    ///     for (u, v) in edges {
+    ///         assert!(position(u) < position(v));
+    ///     }
+    ///     assert!(position(1) < position(5) && position(2) < position(5));
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// // Diamond where the sink has the smallest id, so it is likely to be visited first by dfs.
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(4, 2).is_ok());
+    /// assert!(graph.add_edge(4, 3).is_ok());
+    /// assert!(graph.add_edge(2, 1).is_ok());
+    /// assert!(graph.add_edge(3, 1).is_ok());
+    /// let order = graph.topological_order();
+    /// assert_eq!(order[0], 4);
+    /// assert_eq!(order[3], 1);
+    /// ```
+    pub fn topological_order(&self) -> Vec<usize> {
+        TopologicalSort::new(self).create_order()
+    }
+
+    /// Count distinct paths from `source` to `target`.
+    ///
+    /// Path of zero length is counted when `source` equals `target`.
+    /// If one of vertices not presented in graph, there are no paths.
+    /// Count saturates at [`u64::MAX`].
+    ///
+    /// # Errors
+    /// Returns [`CycleError`] if cycle is reachable from `source`, as number of paths may be infinite.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=5 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(2, 4).is_ok());
+    /// assert!(graph.add_edge(3, 4).is_ok());
+    /// assert!(graph.add_edge(1, 4).is_ok());
+    /// assert_eq!(graph.count_paths(1, 4).unwrap(), 3);
+    /// assert_eq!(graph.count_paths(4, 1).unwrap(), 0);
+    /// assert_eq!(graph.count_paths(1, 5).unwrap(), 0);
+    ///
+    /// assert!(graph.add_edge(4, 5).is_ok());
+    /// assert!(graph.add_edge(5, 2).is_ok());
+    /// let err = graph.count_paths(1, 4).unwrap_err();
+    /// assert_eq!(err.cycle().len(), 3);
+    /// ```
+    pub fn count_paths(&self, source: usize, target: usize) -> Result<u64, CycleError> {
+        if self.get_vertex(source).is_none() || self.get_vertex(target).is_none() {
+            return Ok(0);
+        }
+        // Vertex is in `counts` once all paths from it are counted,
+        // vertices on current dfs path are kept in `path` and `on_path`.
+        let mut counts: HashMap<usize, u64> = HashMap::new();
+        let mut path = vec![source];
+        let mut on_path: HashSet<usize> = HashSet::from([source]);
+        let mut stack = vec![(source, self.neighbours_iter(source))];
+        while let Some((v, neighbours)) = stack.last_mut() {
+            let v = *v;
+            match neighbours.next() {
+                Some(nx) => {
+                    if counts.contains_key(&nx) {
+                        continue;
+                    }
+                    if on_path.contains(&nx) {
+                        let pos = path.iter().position(|&u| u == nx).unwrap();
+                        return Err(CycleError::new(path[pos..].to_vec()));
+                    }
+                    path.push(nx);
+                    on_path.insert(nx);
+                    stack.push((nx, self.neighbours_iter(nx)));
+                }
+                None => {
+                    let count = if v == target {
+                        1
+                    } else {
+                        self.neighbours_iter(v)
+                            .fold(0u64, |acc, nx| acc.saturating_add(counts[&nx]))
+                    };
+                    counts.insert(v, count);
+                    path.pop();
+                    on_path.remove(&v);
+                    stack.pop();
+                }
+            }
+        }
+        Ok(counts[&source])
+    }
+
+    /// Number of incoming edges for each vertex of graph.
+    pub(crate) fn in_degrees(&self) -> HashMap<usize, usize> {
+        self.in_degrees_among(&self.get_vertices_ids())
+    }
+
+    /// Number of incoming edges from `vertices` for each of `vertices`.
+    fn in_degrees_among(&self, vertices: &HashSet<usize>) -> HashMap<usize, usize> {
+        let mut in_degrees: HashMap<usize, usize> = vertices.iter().map(|&v| (v, 0)).collect();
+        for &v in vertices {
+            for nx in self.neighbours_iter(v) {
+                if let Some(degree) = in_degrees.get_mut(&nx) {
+                    *degree += 1;
+                }
+            }
+        }
+        in_degrees
+    }
+
+    /// Find cycle among `remaining` vertices, each of them must have incoming edge from another remaining vertex.
+    ///
+    /// Such set is left by Kahn's algorithm on cyclic graph. Found cycle starts from its minimal vertex.
+    pub(crate) fn find_cycle_among(&self, remaining: &HashSet<usize>) -> CycleError {
+        let mut predecessor = HashMap::new();
+        for &v in remaining {
+            for nx in self.neighbours_iter(v) {
+                if remaining.contains(&nx) {
+                    predecessor.insert(nx, v);
+                }
+            }
+        }
+        let mut path = Vec::new();
+        let mut on_path = HashSet::new();
+        let mut v = *remaining.iter().min().unwrap();
+        while on_path.insert(v) {
+            path.push(v);
+            v = predecessor[&v];
+        }
+        let pos = path.iter().position(|&u| u == v).unwrap();
+        let mut cycle = path.split_off(pos);
+        cycle.reverse();
+        let min_pos = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap();
+        cycle.rotate_left(min_pos);
+        CycleError::new(cycle)
+    }
+
+    /// Split vertices into layers: layer 0 contains vertices without incoming edges,
+    /// each next layer contains vertices with all predecessors in previous layers.
+    ///
+    /// Vertices of each layer are ordered ascending.
+    ///
+    /// # Errors
+    /// Returns [`CycleError`] if graph contains cycle.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=5 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(3, 4).is_ok());
+    /// assert!(graph.add_edge(1, 4).is_ok());
+    /// assert_eq!(graph.topological_layers().unwrap(), vec![vec![1, 2, 5], vec![3], vec![4]]);
+    ///
+    /// assert!(graph.add_edge(4, 2).is_ok());
+    /// let err = graph.topological_layers().unwrap_err();
+    /// assert_eq!(err.cycle(), &[2, 3, 4]);
+    /// ```
+    pub fn topological_layers(&self) -> Result<Vec<Vec<usize>>, CycleError> {
+        let mut in_degrees = self.in_degrees();
+        let mut layer: Vec<_> = in_degrees
+            .iter()
+            .filter(|(_, &degree)| degree == 0)
+            .map(|(&v, _)| v)
+            .collect();
+        let mut layers = Vec::new();
+        let mut processed = 0;
+        while !layer.is_empty() {
+            layer.sort_unstable();
+            let mut next_layer = Vec::new();
+            for &v in &layer {
+                for nx in self.neighbours_iter(v) {
+                    let degree = in_degrees.get_mut(&nx).unwrap();
+                    *degree -= 1;
+                    if *degree == 0 {
+                        next_layer.push(nx);
+                    }
+                }
+            }
+            processed += layer.len();
+            layers.push(layer);
+            layer = next_layer;
+        }
+        if processed < in_degrees.len() {
+            let remaining = in_degrees
+                .into_iter()
+                .filter(|(_, degree)| *degree > 0)
+                .map(|(v, _)| v)
+                .collect();
+            return Err(self.find_cycle_among(&remaining));
+        }
+        Ok(layers)
+    }
+
+    /// Get vertices in topological order, choosing the least vertex by `cmp`
+    /// among vertices with all predecessors already taken.
+    ///
+    /// # Errors
+    /// Returns [`CycleError`] if graph contains cycle.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "c".to_string());
+    /// graph.add_vertex(2, "b".to_string());
+    /// graph.add_vertex(3, "a".to_string());
+    /// graph.add_vertex(4, "d".to_string());
+    /// assert!(graph.add_edge(2, 1).is_ok());
+    /// assert!(graph.add_edge(4, 3).is_ok());
+    /// let order = graph.topological_sort_by(|a, b| a.cmp(&b)).unwrap();
+    /// assert_eq!(order, vec![2, 1, 4, 3]);
+    /// let value = |v| &graph.get_vertex(v).unwrap().value;
+    /// let order = graph.topological_sort_by(|a, b| value(a).cmp(value(b))).unwrap();
+    /// assert_eq!(order, vec![2, 1, 4, 3]);
+    /// let order = graph.topological_sort_by(|a, b| value(b).cmp(value(a))).unwrap();
+    /// assert_eq!(order, vec![4, 2, 1, 3]);
+    ///
+    /// assert!(graph.add_edge(3, 4).is_ok());
+    /// assert!(graph.topological_sort_by(|a, b| a.cmp(&b)).is_err());
+    /// ```
+    pub fn topological_sort_by<F: Fn(usize, usize) -> Ordering>(
+        &self,
+        cmp: F,
+    ) -> Result<Vec<usize>, CycleError> {
+        self.kahn_order(self.in_degrees(), cmp)
+    }
+
+    /// Get topological order of vertices from `in_degrees` by Kahn's algorithm, choosing the least
+    /// vertex by `cmp` among ready ones. Edges must not lead out of `in_degrees` keys.
+    fn kahn_order<F: Fn(usize, usize) -> Ordering>(
+        &self,
+        mut in_degrees: HashMap<usize, usize>,
+        cmp: F,
+    ) -> Result<Vec<usize>, CycleError> {
+        let mut ready: BinaryHeap<_> = in_degrees
+            .iter()
+            .filter(|(_, &degree)| degree == 0)
+            .map(|(&vertex, _)| ReadyVertex { vertex, cmp: &cmp })
+            .collect();
+        let mut order = Vec::with_capacity(in_degrees.len());
+        while let Some(ReadyVertex { vertex, .. }) = ready.pop() {
+            order.push(vertex);
+            for nx in self.neighbours_iter(vertex) {
+                let degree = in_degrees.get_mut(&nx).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    ready.push(ReadyVertex {
+                        vertex: nx,
+                        cmp: &cmp,
+                    });
+                }
+            }
+        }
+        if order.len() < in_degrees.len() {
+            let remaining = in_degrees
+                .into_iter()
+                .filter(|(_, degree)| *degree > 0)
+                .map(|(v, _)| v)
+                .collect();
+            return Err(self.find_cycle_among(&remaining));
+        }
+        Ok(order)
+    }
+
+    /// Get topological order of vertices reachable from `sources`, other vertices are excluded.
+    ///
+    /// Sources absent in graph are ignored. Among vertices with all predecessors already taken
+    /// the one with the least id goes first.
+    ///
+    /// # Errors
+    /// Returns [`CycleError`] if cycle is reachable from `sources`.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=6 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(3, 4).is_ok());
+    /// assert!(graph.add_edge(5, 4).is_ok());
+    /// assert!(graph.add_edge(6, 6).is_ok());
+    /// assert_eq!(graph.topological_order_from(&[3, 2, 10]).unwrap(), vec![2, 3, 4]);
+    /// assert!(graph.topological_order_from(&[6]).is_err());
+    /// ```
+    pub fn topological_order_from(&self, sources: &[usize]) -> Result<Vec<usize>, CycleError> {
+        let mut reachable = HashSet::new();
+        let mut stack: Vec<_> = sources
+            .iter()
+            .copied()
+            .filter(|&v| self.get_vertex(v).is_some())
+            .collect();
+        while let Some(v) = stack.pop() {
+            if reachable.insert(v) {
+                stack.extend(self.neighbours_iter(v));
+            }
+        }
+        self.kahn_order(self.in_degrees_among(&reachable), |a, b| a.cmp(&b))
+    }
+
+    /// Get vertices in dependency order, where edge `u -> v` means that `v` depends on `u`,
+    /// so each vertex goes after all vertices it depends on.
+    ///
+    /// Among independent vertices the one with the least id goes first.
+    ///
+    /// # Errors
+    /// Returns [`CycleError`] with vertices of some dependency cycle.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "app".to_string());
+    /// graph.add_vertex(2, "http".to_string());
+    /// graph.add_vertex(3, "libc".to_string());
+    /// assert!(graph.add_edge(2, 1).is_ok());
+    /// assert!(graph.add_edge(3, 2).is_ok());
+    /// assert!(graph.add_edge(3, 1).is_ok());
+    /// let order: Vec<_> = graph
+    ///     .resolve_order()
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .map(|v| v.value.as_str())
+    ///     .collect();
+    /// assert_eq!(order, vec!["libc", "http", "app"]);
+    ///
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// let err = graph.resolve_order().unwrap_err();
+    /// assert!(err.cycle().contains(&1) && err.cycle().contains(&3));
+    /// ```
+    pub fn resolve_order(&self) -> Result<Vec<&GraphVertex<T>>, CycleError> {
+        Ok(self
+            .topological_sort_by(|a, b| a.cmp(&b))?
+            .into_iter()
+            .map(|v| self.get_vertex(v).unwrap())
+            .collect())
+    }
+}
@@ -0,0 +1,138 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::collections::HashMap;
+use crate::prelude::*;
+use crate::Graph;
+
+/// Kind of edge `u -> v` relative to dfs forest, see [`Graph::classify_edges`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeKind {
+    /// `v` was discovered by this edge.
+    Tree,
+    /// `v` is an ancestor of `u` or `u` itself, such edges exist iff graph has cycle.
+    Back,
+    /// `v` is a descendant of `u`, but was discovered by another edge.
+    Forward,
+    /// `v` is neither ancestor nor descendant of `u`.
+    Cross,
+}
+
+impl<T: FromStr + Display> Graph<T> {
+    /// Get discovery and finish times of vertices reachable from `source` by dfs.
+    ///
+    /// Time starts from 0 and is advanced on each discovery and each finish, so for every vertex
+    /// discovery time is less than finish time, and vertices discovered while `v` is open
+    /// have both times between times of `v`. Neighbours are visited in ascending order of ids.
+    /// If vertex not presented in graph, result is empty.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashMap;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(4, 1).is_ok());
+    /// assert_eq!(
+    ///     graph.dfs_times(1),
+    ///     HashMap::from([(1, (0, 5)), (2, (1, 4)), (3, (2, 3))])
+    /// );
+    ///
+    /// let graph = Graph::path(100_000, |v| v);
+    /// assert_eq!(graph.dfs_times(0)[&99_999], (99_999, 100_000));
+    /// ```
+    pub fn dfs_times(&self, source: usize) -> HashMap<usize, (usize, usize)> {
+        let mut times = HashMap::new();
+        self.dfs_times_from(source, &mut times, &mut HashMap::new(), &mut 0);
+        times
+    }
+
+    /// Classify each edge by its kind relative to dfs forest.
+    ///
+    /// Dfs roots are taken in ascending order of ids and neighbours are visited in ascending order,
+    /// as in [`Graph::dfs_times`], so result is reproducible.
+    ///
+    /// ```
+    /// use trivial_graph::{EdgeKind, Graph};
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(3, 1).is_ok());
+    /// assert!(graph.add_edge(4, 2).is_ok());
+    /// let kinds = graph.classify_edges();
+    /// assert_eq!(kinds.len(), 5);
+    /// assert_eq!(kinds[&(1, 2)], EdgeKind::Tree);
+    /// assert_eq!(kinds[&(2, 3)], EdgeKind::Tree);
+    /// assert_eq!(kinds[&(1, 3)], EdgeKind::Forward);
+    /// assert_eq!(kinds[&(3, 1)], EdgeKind::Back);
+    /// assert_eq!(kinds[&(4, 2)], EdgeKind::Cross);
+    /// ```
+    pub fn classify_edges(&self) -> HashMap<(usize, usize), EdgeKind> {
+        let mut roots: Vec<_> = self.get_vertices_ids().into_iter().collect();
+        roots.sort_unstable();
+        let mut times = HashMap::new();
+        let mut parents = HashMap::new();
+        let mut time = 0;
+        for root in roots {
+            self.dfs_times_from(root, &mut times, &mut parents, &mut time);
+        }
+        self.edges()
+            .map(|(u, v)| {
+                let ((u_discovery, u_finish), (v_discovery, v_finish)) = (times[&u], times[&v]);
+                let kind = if parents.get(&v) == Some(&u) {
+                    EdgeKind::Tree
+                } else if v_discovery <= u_discovery && u_finish <= v_finish {
+                    EdgeKind::Back
+                } else if u_discovery < v_discovery && v_finish < u_finish {
+                    EdgeKind::Forward
+                } else {
+                    EdgeKind::Cross
+                };
+                ((u, v), kind)
+            })
+            .collect()
+    }
+
+    /// Run dfs from `source` unless it is already discovered, recording times into `times`,
+    /// parents of discovered vertices into `parents` and advancing `time`.
+    fn dfs_times_from(
+        &self,
+        source: usize,
+        times: &mut HashMap<usize, (usize, usize)>,
+        parents: &mut HashMap<usize, usize>,
+        time: &mut usize,
+    ) {
+        if self.get_vertex(source).is_none() || times.contains_key(&source) {
+            return;
+        }
+        let sorted_neighbours = |v| {
+            let mut neighbours: Vec<_> = self.neighbours_iter(v).collect();
+            neighbours.sort_unstable();
+            neighbours.into_iter()
+        };
+        times.insert(source, (*time, 0));
+        *time += 1;
+        let mut stack = vec![(source, sorted_neighbours(source))];
+        while let Some((v, neighbours)) = stack.last_mut() {
+            let v = *v;
+            if let Some(nx) = neighbours.find(|nx| !times.contains_key(nx)) {
+                times.insert(nx, (*time, 0));
+                parents.insert(nx, v);
+                *time += 1;
+                stack.push((nx, sorted_neighbours(nx)));
+            } else {
+                times.get_mut(&v).unwrap().1 = *time;
+                *time += 1;
+                stack.pop();
+            }
+        }
+    }
+}
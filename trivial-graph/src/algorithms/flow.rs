@@ -0,0 +1,88 @@
+use alloc::collections::VecDeque;
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::collections::{HashMap, HashSet};
+use crate::prelude::*;
+use crate::Graph;
+
+impl<T: FromStr + Display> Graph<T> {
+    /// Get maximal flow from `source` to `sink` and edges of minimal cut separating them.
+    ///
+    /// Graph has no edge weights, so each edge has unit capacity, and flow value is the number
+    /// of edge-disjoint paths from `source` to `sink`. Cut edges go from vertices reachable from
+    /// `source` in residual network to the rest, sorted by start vertex, then by end vertex.
+    /// If one of vertices not presented in graph or `source` equals `sink`, returns `(0, vec![])`.
+    ///
+    /// Computed by Edmonds–Karp algorithm in `O(V * E^2)` time.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=5 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(2, 4).is_ok());
+    /// assert!(graph.add_edge(3, 4).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(4, 5).is_ok());
+    /// assert_eq!(graph.min_cut(1, 4), (2, vec![(1, 2), (1, 3)]));
+    /// assert_eq!(graph.min_cut(1, 5), (1, vec![(4, 5)]));
+    /// assert_eq!(graph.min_cut(5, 1), (0, vec![]));
+    /// ```
+    pub fn min_cut(&self, source: usize, sink: usize) -> (u64, Vec<(usize, usize)>) {
+        if source == sink || self.get_vertex(source).is_none() || self.get_vertex(sink).is_none() {
+            return (0, vec![]);
+        }
+        let mut capacities: HashMap<(usize, usize), u64> = HashMap::new();
+        let mut residual_neighbours: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for (u, v) in self.edges() {
+            *capacities.entry((u, v)).or_insert(0) += 1;
+            capacities.entry((v, u)).or_insert(0);
+            residual_neighbours.entry(u).or_default().insert(v);
+            residual_neighbours.entry(v).or_default().insert(u);
+        }
+
+        // Bfs in residual network, returns parents of reached vertices.
+        let residual_bfs = |capacities: &HashMap<(usize, usize), u64>| {
+            let mut parents: HashMap<usize, usize> = HashMap::from([(source, source)]);
+            let mut vertex_queue = VecDeque::from([source]);
+            while let Some(v) = vertex_queue.pop_front() {
+                for &nx in residual_neighbours.get(&v).into_iter().flatten() {
+                    if capacities[&(v, nx)] > 0 && !parents.contains_key(&nx) {
+                        parents.insert(nx, v);
+                        vertex_queue.push_back(nx);
+                    }
+                }
+            }
+            parents
+        };
+
+        let mut flow = 0;
+        loop {
+            let parents = residual_bfs(&capacities);
+            if !parents.contains_key(&sink) {
+                let mut cut: Vec<_> = self
+                    .edges()
+                    .filter(|(u, v)| parents.contains_key(u) && !parents.contains_key(v))
+                    .collect();
+                cut.sort_unstable();
+                return (flow, cut);
+            }
+            let mut path = Vec::new();
+            let mut v = sink;
+            while v != source {
+                path.push((parents[&v], v));
+                v = parents[&v];
+            }
+            let bottleneck = path.iter().map(|edge| capacities[edge]).min().unwrap();
+            for (u, v) in path {
+                *capacities.get_mut(&(u, v)).unwrap() -= bottleneck;
+                *capacities.get_mut(&(v, u)).unwrap() += bottleneck;
+            }
+            flow += bottleneck;
+        }
+    }
+}
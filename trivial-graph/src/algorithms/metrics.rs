@@ -0,0 +1,199 @@
+use alloc::collections::VecDeque;
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::collections::HashMap;
+use crate::prelude::*;
+use crate::Graph;
+
+impl<T: FromStr + Display> Graph<T> {
+    /// Distances in edges from `source` to all vertices reachable from it.
+    pub(crate) fn bfs_distances(&self, source: usize) -> HashMap<usize, usize> {
+        let mut distances = HashMap::new();
+        if self.get_vertex(source).is_none() {
+            return distances;
+        }
+        let mut vertex_queue = VecDeque::from([source]);
+        distances.insert(source, 0);
+        while let Some(v) = vertex_queue.pop_front() {
+            let distance = distances[&v] + 1;
+            for nx in self.neighbours_iter(v) {
+                distances.entry(nx).or_insert_with(|| {
+                    vertex_queue.push_back(nx);
+                    distance
+                });
+            }
+        }
+        distances
+    }
+
+    /// Get maximal distance in edges from `vertex` to vertices reachable from it.
+    ///
+    /// Unreachable vertices are not taken into account.
+    /// If vertex not presented in graph, returns `None`.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert_eq!(graph.eccentricity(1), Some(1));
+    /// assert_eq!(graph.eccentricity(3), Some(0));
+    /// assert_eq!(graph.eccentricity(5), None);
+    /// ```
+    pub fn eccentricity(&self, vertex: usize) -> Option<usize> {
+        self.bfs_distances(vertex).into_values().max()
+    }
+
+    /// Get diameter of graph, maximal eccentricity of its vertices.
+    ///
+    /// Returns `None` if graph is empty or some vertex is unreachable from another one.
+    ///
+    /// Note: runs bfs from each vertex, so takes `O(V * (V + E))` time.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=3 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert_eq!(graph.diameter(), None);
+    /// assert!(graph.add_edge(3, 1).is_ok());
+    /// assert_eq!(graph.diameter(), Some(2));
+    /// ```
+    pub fn diameter(&self) -> Option<usize> {
+        let vertex_count = self.get_vertices_ids().len();
+        let mut diameter = None;
+        for v in self.get_vertices_ids() {
+            let distances = self.bfs_distances(v);
+            if distances.len() < vertex_count {
+                return None;
+            }
+            diameter = diameter.max(distances.into_values().max());
+        }
+        diameter
+    }
+
+    /// Get length in edges of the shortest directed cycle, `None` if graph is acyclic.
+    ///
+    /// Self-loop is a cycle of length 1, pair of edges `u -> v` and `v -> u` is a cycle of length 2.
+    ///
+    /// Note: runs bfs from each vertex, so takes `O(V * (V + E))` time.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::cycle(5, |v| v.to_string());
+    /// assert_eq!(graph.girth(), Some(5));
+    /// assert!(graph.add_edge(3, 1).is_ok());
+    /// assert_eq!(graph.girth(), Some(3));
+    /// assert!(graph.add_edge(4, 4).is_ok());
+    /// assert_eq!(graph.girth(), Some(1));
+    /// assert_eq!(Graph::path(5, |v| v.to_string()).girth(), None);
+    /// ```
+    pub fn girth(&self) -> Option<usize> {
+        let mut girth: Option<usize> = None;
+        for source in self.get_vertices_ids() {
+            let mut distances: HashMap<usize, usize> = HashMap::from([(source, 0)]);
+            let mut vertex_queue = VecDeque::from([source]);
+            'bfs: while let Some(v) = vertex_queue.pop_front() {
+                let distance = distances[&v] + 1;
+                if girth.is_some_and(|girth| distance >= girth) {
+                    break;
+                }
+                for nx in self.neighbours_iter(v) {
+                    if nx == source {
+                        girth = Some(distance);
+                        break 'bfs;
+                    }
+                    distances.entry(nx).or_insert_with(|| {
+                        vertex_queue.push_back(nx);
+                        distance
+                    });
+                }
+            }
+        }
+        girth
+    }
+
+    /// Get betweenness centrality of each vertex: sum over ordered pairs `(s, t)` of other vertices
+    /// of the fraction of shortest paths from `s` to `t` passing through it.
+    ///
+    /// Edge direction is taken into account and values are not normalized.
+    /// Computed by Brandes' algorithm in `O(V * E)` time.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 0..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// for i in 1..=4 {
+    ///     assert!(graph.add_edge(0, i).is_ok());
+    ///     assert!(graph.add_edge(i, 0).is_ok());
+    /// }
+    /// let centrality = graph.betweenness_centrality();
+    /// assert_eq!(centrality[&0], 12.0);
+    /// for i in 1..=4 {
+    ///     assert_eq!(centrality[&i], 0.0);
+    /// }
+    ///
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(2, 4).is_ok());
+    /// assert!(graph.add_edge(3, 4).is_ok());
+    /// let centrality = graph.betweenness_centrality();
+    /// assert_eq!(centrality[&2], 0.5);
+    /// assert_eq!(centrality[&3], 0.5);
+    /// ```
+    pub fn betweenness_centrality(&self) -> HashMap<usize, f64> {
+        let mut centrality: HashMap<usize, f64> = self
+            .get_vertices_ids()
+            .into_iter()
+            .map(|v| (v, 0.0))
+            .collect();
+        for source in self.get_vertices_ids() {
+            let mut order = Vec::new();
+            let mut predecessors: HashMap<usize, Vec<usize>> = HashMap::new();
+            let mut path_counts: HashMap<usize, f64> = HashMap::from([(source, 1.0)]);
+            let mut distances: HashMap<usize, usize> = HashMap::from([(source, 0)]);
+            let mut vertex_queue = VecDeque::from([source]);
+            while let Some(v) = vertex_queue.pop_front() {
+                order.push(v);
+                let distance = distances[&v] + 1;
+                for nx in self.neighbours_iter(v) {
+                    let nx_distance = *distances.entry(nx).or_insert_with(|| {
+                        vertex_queue.push_back(nx);
+                        distance
+                    });
+                    if nx_distance == distance {
+                        *path_counts.entry(nx).or_insert(0.0) += path_counts[&v];
+                        predecessors.entry(nx).or_default().push(v);
+                    }
+                }
+            }
+
+            let mut dependencies: HashMap<usize, f64> = HashMap::new();
+            for &w in order.iter().rev() {
+                let dependency = dependencies.get(&w).copied().unwrap_or(0.0);
+                for &v in predecessors.get(&w).into_iter().flatten() {
+                    *dependencies.entry(v).or_insert(0.0) +=
+                        path_counts[&v] / path_counts[&w] * (1.0 + dependency);
+                }
+                if w != source {
+                    *centrality.get_mut(&w).unwrap() += dependency;
+                }
+            }
+        }
+        centrality
+    }
+}
@@ -0,0 +1,12 @@
+pub use dfs::EdgeKind;
+pub use operations::GraphDiff;
+
+mod coloring;
+mod dag;
+mod dfs;
+mod flow;
+mod metrics;
+mod operations;
+mod scc;
+mod trees;
+mod walk;
@@ -0,0 +1,288 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::collections::HashSet;
+use crate::prelude::*;
+use crate::Graph;
+
+/// Difference between two versions of graph, see [`Graph::diff`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GraphDiff {
+    /// Vertices present only in new version.
+    pub added_vertices: HashSet<usize>,
+    /// Vertices present only in old version.
+    pub removed_vertices: HashSet<usize>,
+    /// Vertices present in both versions with different values.
+    pub changed_values: HashSet<usize>,
+    /// Edges present only in new version.
+    pub added_edges: HashSet<(usize, usize)>,
+    /// Edges present only in old version, including edges of removed vertices.
+    pub removed_edges: HashSet<(usize, usize)>,
+}
+
+impl GraphDiff {
+    /// Check if versions have the same vertices, values and edges.
+    pub fn is_empty(&self) -> bool {
+        self.added_vertices.is_empty()
+            && self.removed_vertices.is_empty()
+            && self.changed_values.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+    }
+}
+
+impl<T: FromStr + Display> Graph<T> {
+    /// Get complement of graph: graph with the same vertices where edge `u -> v` with `u != v`
+    /// is present iff it is absent in this graph.
+    ///
+    /// Edge direction is taken into account. Result may contain `O(V^2)` edges.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// graph.add_vertex(3, "node3".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 2).is_ok());
+    /// let complement = graph.complement();
+    /// assert_eq!(complement.get_vertex(3).unwrap().value, "node3");
+    /// assert_eq!(complement.get_neighbours(1), Some(HashSet::from([3])));
+    /// assert_eq!(complement.get_neighbours(2), Some(HashSet::from([1, 3])));
+    /// assert_eq!(complement.get_neighbours(3), Some(HashSet::from([1, 2])));
+    /// ```
+    pub fn complement(&self) -> Graph<T>
+    where
+        T: Clone,
+    {
+        let mut complement = self.clone_vertices();
+        let vertices = self.get_vertices_ids();
+        for &u in &vertices {
+            for &v in &vertices {
+                if u != v && !self.has_edge(u, v) {
+                    complement.add_edge(u, v).unwrap();
+                }
+            }
+        }
+        complement
+    }
+
+    /// Get union of graphs: graph with vertices and edges present in any of them.
+    ///
+    /// If vertex is present in both graphs, its value is taken from `self`.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// let mut other = Graph::new();
+    /// other.add_vertex(2, "other2".to_string());
+    /// other.add_vertex(3, "other3".to_string());
+    /// assert!(other.add_edge(2, 3).is_ok());
+    /// let union = graph.union(&other);
+    /// assert_eq!(union.get_vertices_ids(), HashSet::from([1, 2, 3]));
+    /// assert_eq!(union.get_vertex(2).unwrap().value, "node2");
+    /// assert_eq!(union.get_neighbours(1), Some(HashSet::from([2])));
+    /// assert_eq!(union.get_neighbours(2), Some(HashSet::from([3])));
+    /// ```
+    pub fn union(&self, other: &Self) -> Graph<T>
+    where
+        T: Clone,
+    {
+        let mut union = self.clone_vertices();
+        for v in other.get_vertices_ids() {
+            if union.get_vertex(v).is_none() {
+                union.insert_vertex(other.get_vertex(v).unwrap().clone());
+            }
+        }
+        for graph in [self, other] {
+            for v in graph.get_vertices_ids() {
+                for nx in graph.neighbours_iter(v) {
+                    union.add_edge(v, nx).unwrap();
+                }
+            }
+        }
+        union
+    }
+
+    /// Get intersection of graphs: graph with vertices and edges present in both of them.
+    ///
+    /// Values of vertices are taken from `self`. Result maintains predecessor index if `self` does.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// let mut other = Graph::new();
+    /// for i in 1..=3 {
+    ///     graph.add_vertex(i, i.to_string());
+    ///     other.add_vertex(i + 1, (i + 1).to_string());
+    /// }
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(3, 2).is_ok());
+    /// assert!(other.add_edge(2, 3).is_ok());
+    /// assert!(other.add_edge(3, 4).is_ok());
+    /// let intersection = graph.intersection(&other);
+    /// assert_eq!(intersection.get_vertices_ids(), HashSet::from([2, 3]));
+    /// assert_eq!(intersection.get_neighbours(2), Some(HashSet::from([3])));
+    /// assert_eq!(intersection.get_neighbours(3), Some(HashSet::new()));
+    ///
+    /// let indexed = Graph::with_predecessor_index();
+    /// assert!(indexed.intersection(&other).has_predecessor_index());
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Graph<T>
+    where
+        T: Clone,
+    {
+        let mut intersection = self.empty_like();
+        for v in self.get_vertices_ids() {
+            if other.get_vertex(v).is_some() {
+                intersection.insert_vertex(self.get_vertex(v).unwrap().clone());
+            }
+        }
+        for v in intersection.get_vertices_ids() {
+            for nx in self.neighbours_iter(v) {
+                if other.has_edge(v, nx) {
+                    intersection.add_edge(v, nx).unwrap();
+                }
+            }
+        }
+        intersection
+    }
+
+    /// Get vertices whose out-degree (number of outgoing edges, self-loop counts once) lies in `[min, max]`,
+    /// both bounds inclusive.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(1, 4).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(3, 3).is_ok());
+    /// assert_eq!(graph.vertices_with_degree(1, 2), HashSet::from([2, 3]));
+    /// assert_eq!(graph.vertices_with_degree(0, 0), HashSet::from([4]));
+    /// assert_eq!(graph.vertices_with_degree(2, 1), HashSet::new());
+    /// ```
+    pub fn vertices_with_degree(&self, min: usize, max: usize) -> HashSet<usize> {
+        self.get_vertices_ids()
+            .into_iter()
+            .filter(|&v| (min..=max).contains(&self.neighbour_count(v).unwrap()))
+            .collect()
+    }
+
+    /// Get subgraph induced by [`Graph::vertices_with_degree`]: those vertices and edges between them.
+    ///
+    /// Degrees are counted in this graph, so in result vertices may have less outgoing edges than `min`.
+    /// Labels of kept edges are copied. Result maintains predecessor index if `self` does.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(1, 4).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(3, 1).is_ok());
+    /// assert!(graph.set_edge_label(2, 3, "uses".to_string()).is_ok());
+    /// let subgraph = graph.subgraph_by_degree(1, 2);
+    /// assert_eq!(subgraph.get_vertices_ids(), HashSet::from([2, 3]));
+    /// assert_eq!(subgraph.get_neighbours(2), Some(HashSet::from([3])));
+    /// assert_eq!(subgraph.get_neighbours(3), Some(HashSet::new()));
+    /// assert_eq!(subgraph.edge_label(2, 3), Some("uses"));
+    ///
+    /// let indexed = Graph::<String>::with_predecessor_index();
+    /// assert!(indexed.subgraph_by_degree(0, 1).has_predecessor_index());
+    /// ```
+    pub fn subgraph_by_degree(&self, min: usize, max: usize) -> Graph<T>
+    where
+        T: Clone,
+    {
+        let vertices = self.vertices_with_degree(min, max);
+        let mut subgraph = self.empty_like();
+        for &v in &vertices {
+            subgraph.insert_vertex(self.get_vertex(v).unwrap().clone());
+        }
+        for &v in &vertices {
+            for nx in self.neighbours_iter(v) {
+                if vertices.contains(&nx) {
+                    subgraph.add_edge(v, nx).unwrap();
+                    if let Some(label) = self.edge_label(v, nx) {
+                        subgraph.set_edge_label(v, nx, label.to_string()).unwrap();
+                    }
+                }
+            }
+        }
+        subgraph
+    }
+
+    /// Get changes made to this graph to get `other`: `self` is treated as old version, `other` as new one.
+    ///
+    /// Vertex attributes and edge labels are not compared.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut old = Graph::new();
+    /// for i in 1..=3 {
+    ///     old.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(old.add_edge(1, 2).is_ok());
+    /// assert!(old.add_edge(2, 3).is_ok());
+    /// assert!(old.diff(&old).is_empty());
+    ///
+    /// let mut new = Graph::new();
+    /// new.add_vertex(1, "one".to_string());
+    /// new.add_vertex(2, "2".to_string());
+    /// new.add_vertex(4, "4".to_string());
+    /// assert!(new.add_edge(2, 1).is_ok());
+    /// assert!(new.add_edge(2, 4).is_ok());
+    /// let diff = old.diff(&new);
+    /// assert!(!diff.is_empty());
+    /// assert_eq!(diff.added_vertices, HashSet::from([4]));
+    /// assert_eq!(diff.removed_vertices, HashSet::from([3]));
+    /// assert_eq!(diff.changed_values, HashSet::from([1]));
+    /// assert_eq!(diff.added_edges, HashSet::from([(2, 1), (2, 4)]));
+    /// assert_eq!(diff.removed_edges, HashSet::from([(1, 2), (2, 3)]));
+    /// ```
+    pub fn diff(&self, other: &Self) -> GraphDiff
+    where
+        T: PartialEq,
+    {
+        let mut diff = GraphDiff::default();
+        for v in self.into_iter() {
+            match other.get_vertex(v.id) {
+                Some(other_v) if other_v.value != v.value => {
+                    diff.changed_values.insert(v.id);
+                }
+                Some(_) => {}
+                None => {
+                    diff.removed_vertices.insert(v.id);
+                }
+            }
+        }
+        for v in other.into_iter() {
+            if self.get_vertex(v.id).is_none() {
+                diff.added_vertices.insert(v.id);
+            }
+        }
+        let edges: HashSet<_> = self.edges().collect();
+        let other_edges: HashSet<_> = other.edges().collect();
+        diff.added_edges = other_edges.difference(&edges).copied().collect();
+        diff.removed_edges = edges.difference(&other_edges).copied().collect();
+        diff
+    }
+}
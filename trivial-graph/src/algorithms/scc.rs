@@ -0,0 +1,193 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::collections::{HashMap, HashSet};
+use crate::prelude::*;
+use crate::Graph;
+
+impl<T: FromStr + Display> Graph<T> {
+    /// Get strongly connected components of graph: maximal sets of vertices reachable from each other.
+    ///
+    /// Vertices of each component are sorted ascending. Components are in reverse topological order:
+    /// if edge leads from one component to another, the latter goes first.
+    /// Computed by Tarjan's algorithm in `O(V + E)` time.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=5 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 1).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(3, 4).is_ok());
+    /// assert!(graph.add_edge(4, 5).is_ok());
+    /// assert!(graph.add_edge(5, 3).is_ok());
+    /// assert_eq!(graph.strongly_connected_components(), vec![vec![3, 4, 5], vec![1, 2]]);
+    ///
+    /// let graph = Graph::path(100_000, |v| v);
+    /// assert_eq!(graph.strongly_connected_components().len(), 100_000);
+    /// ```
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let sorted_neighbours = |v| {
+            let mut neighbours: Vec<_> = self.neighbours_iter(v).collect();
+            neighbours.sort_unstable();
+            neighbours.into_iter()
+        };
+        let mut roots: Vec<_> = self.get_vertices_ids().into_iter().collect();
+        roots.sort_unstable();
+
+        let mut indices = HashMap::new();
+        let mut low_links = HashMap::new();
+        let mut on_stack = HashSet::new();
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        for root in roots {
+            if indices.contains_key(&root) {
+                continue;
+            }
+            let mut call_stack = Vec::new();
+            let mut next = Some(root);
+            loop {
+                if let Some(v) = next.take() {
+                    indices.insert(v, indices.len());
+                    low_links.insert(v, indices[&v]);
+                    on_stack.insert(v);
+                    stack.push(v);
+                    call_stack.push((v, sorted_neighbours(v)));
+                }
+                let Some((v, neighbours)) = call_stack.last_mut() else {
+                    break;
+                };
+                let v = *v;
+                if let Some(nx) = neighbours.next() {
+                    if !indices.contains_key(&nx) {
+                        next = Some(nx);
+                    } else if on_stack.contains(&nx) {
+                        let low_link = low_links[&v].min(indices[&nx]);
+                        low_links.insert(v, low_link);
+                    }
+                    continue;
+                }
+                call_stack.pop();
+                if let Some((parent, _)) = call_stack.last() {
+                    let low_link = low_links[parent].min(low_links[&v]);
+                    low_links.insert(*parent, low_link);
+                }
+                if low_links[&v] == indices[&v] {
+                    let mut component = Vec::new();
+                    loop {
+                        let u = stack.pop().unwrap();
+                        on_stack.remove(&u);
+                        component.push(u);
+                        if u == v {
+                            break;
+                        }
+                    }
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+
+    /// Index of strongly connected component in [`Graph::strongly_connected_components`] for each vertex.
+    pub(crate) fn scc_ids(&self) -> HashMap<usize, usize> {
+        self.strongly_connected_components()
+            .into_iter()
+            .enumerate()
+            .flat_map(|(id, component)| component.into_iter().map(move |v| (v, id)))
+            .collect()
+    }
+
+    /// Check whether vertices `a` and `b` are reachable from each other.
+    ///
+    /// If one of vertices not presented in graph, returns `None`.
+    ///
+    /// Note: components are not cached, each call computes them in `O(V + E)` time.
+    /// For many queries use [`Graph::strongly_connected_components`].
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::cycle(3, |v| v.to_string());
+    /// graph.add_vertex(3, "3".to_string());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert_eq!(graph.same_scc(0, 2), Some(true));
+    /// assert_eq!(graph.same_scc(0, 3), Some(false));
+    /// assert_eq!(graph.same_scc(0, 4), None);
+    /// ```
+    pub fn same_scc(&self, a: usize, b: usize) -> Option<bool> {
+        if self.get_vertex(a).is_none() || self.get_vertex(b).is_none() {
+            return None;
+        }
+        let scc_ids = self.scc_ids();
+        Some(scc_ids[&a] == scc_ids[&b])
+    }
+
+    /// Check whether each vertex is reachable from each other one following edge direction.
+    ///
+    /// Empty graph and graph with single vertex are strongly connected.
+    ///
+    /// Runs one bfs on graph and one on graph with reversed edges, so takes `O(V + E)` time.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// assert!(Graph::<i32>::new().is_strongly_connected());
+    /// let mut graph = Graph::cycle(4, |v| v.to_string());
+    /// assert!(graph.is_strongly_connected());
+    /// assert!(graph.add_edge(0, 2).is_ok());
+    /// assert!(graph.is_strongly_connected());
+    /// graph.remove_edge(3, 0);
+    /// assert!(!graph.is_strongly_connected());
+    /// ```
+    pub fn is_strongly_connected(&self) -> bool {
+        let Some(start) = self.min_vertex_id() else {
+            return true;
+        };
+        let vertex_count = self.get_vertices_ids().len();
+        if self.bfs_distances(start).len() < vertex_count {
+            return false;
+        }
+        let mut reversed: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (v, u) in self.edges() {
+            reversed.entry(u).or_default().push(v);
+        }
+        let mut reached: HashSet<usize> = HashSet::from([start]);
+        let mut stack = vec![start];
+        while let Some(v) = stack.pop() {
+            for &nx in reversed.get(&v).into_iter().flatten() {
+                if reached.insert(nx) {
+                    stack.push(nx);
+                }
+            }
+        }
+        reached.len() == vertex_count
+    }
+
+    /// Get strongly connected components in topological order of condensation:
+    /// for each edge `u -> v` between different components, component of `u` goes first.
+    ///
+    /// Works on cyclic graphs too: groups of several vertices, or single vertex with self-loop, are cycles.
+    /// For acyclic graph each group has single vertex and groups form topological order.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=5 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(5, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(3, 2).is_ok());
+    /// assert!(graph.add_edge(3, 1).is_ok());
+    /// assert!(graph.add_edge(1, 4).is_ok());
+    /// assert_eq!(graph.robust_order(), vec![vec![5], vec![2, 3], vec![1], vec![4]]);
+    /// ```
+    pub fn robust_order(&self) -> Vec<Vec<usize>> {
+        let mut components = self.strongly_connected_components();
+        components.reverse();
+        components
+    }
+}
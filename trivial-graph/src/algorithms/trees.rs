@@ -0,0 +1,172 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::collections::{HashMap, HashSet};
+use crate::prelude::*;
+use crate::Graph;
+
+impl<T: FromStr + Display> Graph<T> {
+    /// Check whether graph, viewed as undirected, has no cycles.
+    ///
+    /// Edges `u -> v` and `v -> u` are counted as one undirected edge, self-loop is a cycle.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 1).is_ok());
+    /// assert!(graph.add_edge(3, 4).is_ok());
+    /// assert!(graph.is_forest());
+    /// assert!(graph.add_edge(4, 4).is_ok());
+    /// assert!(!graph.is_forest());
+    /// ```
+    pub fn is_forest(&self) -> bool {
+        self.undirected_forest_components().is_some()
+    }
+
+    /// Check whether graph, viewed as undirected, is connected and has no cycles.
+    ///
+    /// Empty graph is not a tree.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// assert!(!graph.is_tree());
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(3, 1).is_ok());
+    /// assert!(!graph.is_tree());
+    /// assert!(graph.add_edge(4, 3).is_ok());
+    /// assert!(graph.is_tree());
+    /// assert!(graph.add_edge(2, 4).is_ok());
+    /// assert!(!graph.is_tree());
+    /// ```
+    pub fn is_tree(&self) -> bool {
+        self.undirected_forest_components() == Some(1)
+    }
+
+    /// Number of undirected connected components if graph is a forest, `None` otherwise.
+    fn undirected_forest_components(&self) -> Option<usize> {
+        fn find(parent: &mut HashMap<usize, usize>, mut v: usize) -> usize {
+            while parent[&v] != v {
+                let grandparent = parent[&parent[&v]];
+                parent.insert(v, grandparent);
+                v = grandparent;
+            }
+            v
+        }
+
+        let mut parent: HashMap<usize, usize> = self
+            .get_vertices_ids()
+            .into_iter()
+            .map(|v| (v, v))
+            .collect();
+        let mut components = parent.len();
+        for (u, v) in self.undirected_edges() {
+            let (root_u, root_v) = (find(&mut parent, u), find(&mut parent, v));
+            if root_u == root_v {
+                return None;
+            }
+            parent.insert(root_u, root_v);
+            components -= 1;
+        }
+        Some(components)
+    }
+
+    /// Get biconnected components of graph viewed as undirected: maximal sets of edges where
+    /// any two edges lie on common simple cycle, or single edges which are bridges.
+    ///
+    /// Edges are given as `(min, max)` pairs, self-loops are ignored. Edges of each component are sorted,
+    /// components are sorted by their first edge.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// // Triangle 1-2-3 and edge 3-4 hanging from it.
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(4, 3).is_ok());
+    /// assert!(graph.add_edge(4, 4).is_ok());
+    /// assert_eq!(
+    ///     graph.biconnected_components(),
+    ///     vec![vec![(1, 2), (1, 3), (2, 3)], vec![(3, 4)]]
+    /// );
+    ///
+    /// let graph = Graph::path(100_000, |v| v);
+    /// assert_eq!(graph.biconnected_components().len(), 99_999);
+    /// ```
+    pub fn biconnected_components(&self) -> Vec<Vec<(usize, usize)>> {
+        let mut adjacent: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (u, v) in self.undirected_edges() {
+            if u != v {
+                adjacent.entry(u).or_default().push(v);
+                adjacent.entry(v).or_default().push(u);
+            }
+        }
+        let mut roots: Vec<_> = adjacent.keys().copied().collect();
+        roots.sort_unstable();
+
+        let mut discovery = HashMap::new();
+        let mut low = HashMap::new();
+        let mut edge_stack = Vec::new();
+        let mut components = Vec::new();
+        for root in roots {
+            if discovery.contains_key(&root) {
+                continue;
+            }
+            discovery.insert(root, discovery.len());
+            low.insert(root, discovery[&root]);
+            let mut call_stack = vec![(root, None, adjacent[&root].iter())];
+            while let Some((v, parent, neighbours)) = call_stack.last_mut() {
+                let (v, parent) = (*v, *parent);
+                if let Some(&nx) = neighbours.next() {
+                    if Some(nx) == parent {
+                        continue;
+                    }
+                    if let Some(&nx_discovery) = discovery.get(&nx) {
+                        if nx_discovery < discovery[&v] {
+                            edge_stack.push((v, nx));
+                            let v_low = low[&v].min(nx_discovery);
+                            low.insert(v, v_low);
+                        }
+                    } else {
+                        edge_stack.push((v, nx));
+                        discovery.insert(nx, discovery.len());
+                        low.insert(nx, discovery[&nx]);
+                        call_stack.push((nx, Some(v), adjacent[&nx].iter()));
+                    }
+                    continue;
+                }
+                call_stack.pop();
+                let Some(parent) = parent else {
+                    continue;
+                };
+                let parent_low = low[&parent].min(low[&v]);
+                low.insert(parent, parent_low);
+                if low[&v] >= discovery[&parent] {
+                    let mut component = HashSet::new();
+                    while let Some((a, b)) = edge_stack.pop() {
+                        component.insert((a.min(b), a.max(b)));
+                        if (a, b) == (parent, v) {
+                            break;
+                        }
+                    }
+                    let mut component: Vec<_> = component.into_iter().collect();
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+        components.sort_unstable();
+        components
+    }
+}
@@ -0,0 +1,46 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::generators::SplitMix64;
+use crate::prelude::*;
+use crate::Graph;
+
+impl<T: FromStr + Display> Graph<T> {
+    /// Make random walk of at most `steps` steps from `start`, moving each step into uniformly chosen
+    /// out-neighbour. Walk stops early in vertex without outgoing edges.
+    ///
+    /// Returns ids of visited vertices including `start`, empty if `start` not presented in graph.
+    /// The same `seed` gives the same walk on the same graph.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let graph = Graph::complete(5, |v| v.to_string());
+    /// let walk = graph.random_walk(0, 10, 42);
+    /// assert_eq!(walk.len(), 11);
+    /// assert_eq!(walk[0], 0);
+    /// assert!(walk.windows(2).all(|step| graph.has_edge(step[0], step[1])));
+    /// assert_eq!(walk, graph.random_walk(0, 10, 42));
+    ///
+    /// let graph = Graph::path(3, |v| v.to_string());
+    /// assert_eq!(graph.random_walk(0, 10, 1), vec![0, 1, 2]);
+    /// assert!(graph.random_walk(5, 10, 1).is_empty());
+    /// ```
+    pub fn random_walk(&self, start: usize, steps: usize, seed: u64) -> Vec<usize> {
+        if self.get_vertex(start).is_none() {
+            return vec![];
+        }
+        let mut rng = SplitMix64::new(seed);
+        let mut walk = vec![start];
+        let mut v = start;
+        for _ in 0..steps {
+            let mut neighbours: Vec<_> = self.neighbours_iter(v).collect();
+            if neighbours.is_empty() {
+                break;
+            }
+            neighbours.sort_unstable();
+            v = neighbours[(rng.next_u64() % neighbours.len() as u64) as usize];
+            walk.push(v);
+        }
+        walk
+    }
+}
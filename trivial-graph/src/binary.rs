@@ -0,0 +1,172 @@
+use std::fmt::Display;
+use std::io::{self, Read, Write};
+use std::str::FromStr;
+
+use crate::Graph;
+
+/// Compact binary encoding of vertex values, used by [`Graph::write_binary`] and [`Graph::read_binary`].
+///
+/// Numbers are encoded as little-endian bytes, strings as length followed by UTF-8 bytes.
+///
+/// ```
+/// use trivial_graph::BinaryEncode;
+/// let mut buf = Vec::new();
+/// assert!("node".to_string().encode(&mut buf).is_ok());
+/// assert!(42u32.encode(&mut buf).is_ok());
+/// let mut reader = buf.as_slice();
+/// assert_eq!(String::decode(&mut reader).unwrap(), "node");
+/// assert_eq!(u32::decode(&mut reader).unwrap(), 42);
+/// ```
+pub trait BinaryEncode: Sized {
+    /// Write value to `w`.
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()>;
+    /// Read value written by [`BinaryEncode::encode`] from `r`.
+    ///
+    /// # Errors
+    /// Returns error of reader, [`io::ErrorKind::UnexpectedEof`] if input ends too early
+    /// or [`io::ErrorKind::InvalidData`] if input is not a valid encoding.
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self>;
+}
+
+macro_rules! impl_binary_encode_num {
+    ($($t:ty),*) => {
+        $(
+            impl BinaryEncode for $t {
+                fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+                    w.write_all(&self.to_le_bytes())
+                }
+
+                fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+                    let mut buf = [0; std::mem::size_of::<$t>()];
+                    r.read_exact(&mut buf)?;
+                    Ok(<$t>::from_le_bytes(buf))
+                }
+            }
+        )*
+    };
+}
+
+impl_binary_encode_num!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+/// `usize` and `isize` are encoded as 64-bit numbers, so encoding doesn't depend on platform.
+impl BinaryEncode for usize {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (*self as u64).encode(w)
+    }
+
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        usize::try_from(u64::decode(r)?)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+impl BinaryEncode for isize {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (*self as i64).encode(w)
+    }
+
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        isize::try_from(i64::decode(r)?)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+impl BinaryEncode for bool {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (*self as u8).encode(w)
+    }
+
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        match u8::decode(r)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            byte => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid bool byte {byte}"),
+            )),
+        }
+    }
+}
+
+impl BinaryEncode for String {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.len().encode(w)?;
+        w.write_all(self.as_bytes())
+    }
+
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        let len = usize::decode(r)?;
+        let mut buf = Vec::new();
+        r.take(len as u64).read_to_end(&mut buf)?;
+        if buf.len() < len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        String::from_utf8(buf).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+impl<T: FromStr + Display + BinaryEncode> Graph<T> {
+    /// Write graph in compact binary format: number of vertices, pairs of id and value,
+    /// then number of edges and pairs of their ends. Vertices and edges are sorted.
+    ///
+    /// Reading it back with [`Graph::read_binary`] is much faster than parsing text format.
+    /// Vertex attributes and edge labels are not written.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node with spaces".to_string());
+    /// graph.add_vertex(3, String::new());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 2).is_ok());
+    /// assert!(graph.add_edge(3, 1).is_ok());
+    /// let mut buf = Vec::new();
+    /// assert!(graph.write_binary(&mut buf).is_ok());
+    /// let read_graph = Graph::<String>::read_binary(&mut buf.as_slice()).unwrap();
+    /// assert_eq!(read_graph, graph);
+    ///
+    /// let truncated = &buf[..buf.len() - 1];
+    /// assert!(Graph::<String>::read_binary(&mut &truncated[..]).is_err());
+    /// ```
+    pub fn write_binary<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let mut vertices: Vec<_> = self.into_iter().collect();
+        vertices.sort_unstable_by_key(|v| v.id);
+        vertices.len().encode(w)?;
+        for v in vertices {
+            v.id.encode(w)?;
+            v.value.encode(w)?;
+        }
+        let edges = self.edges_sorted();
+        edges.len().encode(w)?;
+        for (from, to) in edges {
+            from.encode(w)?;
+            to.encode(w)?;
+        }
+        Ok(())
+    }
+
+    /// Read graph written by [`Graph::write_binary`].
+    ///
+    /// # Errors
+    /// Returns error of reader, [`io::ErrorKind::UnexpectedEof`] if input is truncated
+    /// or [`io::ErrorKind::InvalidData`] if value can't be decoded or edge refers to missing vertex.
+    pub fn read_binary<R: Read>(r: &mut R) -> io::Result<Self> {
+        let mut graph = Self::new();
+        let vertex_count = usize::decode(r)?;
+        for _ in 0..vertex_count {
+            let id = usize::decode(r)?;
+            let value = T::decode(r)?;
+            graph.add_vertex(id, value);
+        }
+        let edge_count = usize::decode(r)?;
+        for _ in 0..edge_count {
+            let from = usize::decode(r)?;
+            let to = usize::decode(r)?;
+            graph
+                .add_edge(from, to)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        }
+        Ok(graph)
+    }
+}
@@ -0,0 +1,20 @@
+//! Hash collections used in public API of the crate.
+//!
+//! With default `std` feature these are `std::collections` types. Without it the crate is `no_std`
+//! and they are `hashbrown` types, enabled by `hashbrown` feature, so code which should build in both configurations
+//! can name them through this module.
+//!
+//! ```
+//! use trivial_graph::collections::HashSet;
+//! use trivial_graph::Graph;
+//! let mut graph = Graph::new();
+//! graph.add_vertex(1, 10);
+//! graph.add_vertex(2, 20);
+//! assert!(graph.add_edge(1, 2).is_ok());
+//! assert_eq!(graph.get_neighbours(1), Some(HashSet::from([2])));
+//! ```
+
+#[cfg(not(feature = "std"))]
+pub use hashbrown::{hash_map, hash_set, HashMap, HashSet};
+#[cfg(feature = "std")]
+pub use std::collections::{hash_map, hash_set, HashMap, HashSet};
@@ -0,0 +1,128 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::Graph;
+
+/// Small seedable pseudo-random generator (SplitMix64), enough for reproducible test graphs.
+pub(crate) struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniformly distributed number in `[0, 1)`.
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+impl<T: FromStr + Display> Graph<T> {
+    /// Creates graph with vertices `0..n` without edges, values are produced by `value_fn` from ids.
+    fn with_vertices(n: usize, value_fn: impl Fn(usize) -> T) -> Self {
+        let mut graph = Self::new();
+        for v in 0..n {
+            graph.add_vertex(v, value_fn(v));
+        }
+        graph
+    }
+
+    /// Creates random directed graph with vertices `0..n` by Erdős–Rényi model:
+    /// each edge `u -> v` with `u != v` is present with probability `p`.
+    ///
+    /// Graphs created with the same `seed` are equal.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let graph = Graph::gnp_random(10, 0.5, 42, |v| v.to_string());
+    /// let same_graph = Graph::gnp_random(10, 0.5, 42, |v| v.to_string());
+    /// assert_eq!(graph.get_vertices_ids().len(), 10);
+    /// for v in 0..10 {
+    ///     assert_eq!(graph.get_neighbours(v), same_graph.get_neighbours(v));
+    ///     assert!(!graph.get_neighbours(v).unwrap().contains(&v));
+    /// }
+    ///
+    /// let graph = Graph::gnp_random(10, 0.0, 42, |v| v.to_string());
+    /// assert!((0..10).all(|v| graph.get_neighbours(v).unwrap().is_empty()));
+    /// ```
+    pub fn gnp_random(n: usize, p: f64, seed: u64, value_fn: impl Fn(usize) -> T) -> Self {
+        let mut graph = Self::with_vertices(n, value_fn);
+        let mut rng = SplitMix64::new(seed);
+        for u in 0..n {
+            for v in 0..n {
+                if u != v && rng.next_f64() < p {
+                    graph.add_edge(u, v).unwrap();
+                }
+            }
+        }
+        graph
+    }
+
+    /// Creates path `0 -> 1 -> ... -> n - 1`.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let graph = Graph::path(3, |v| v.to_string());
+    /// assert_eq!(graph.get_neighbours(0), Some(HashSet::from([1])));
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::from([2])));
+    /// assert_eq!(graph.get_neighbours(2), Some(HashSet::new()));
+    /// ```
+    pub fn path(n: usize, value_fn: impl Fn(usize) -> T) -> Self {
+        let mut graph = Self::with_vertices(n, value_fn);
+        for v in 1..n {
+            graph.add_edge(v - 1, v).unwrap();
+        }
+        graph
+    }
+
+    /// Creates cycle `0 -> 1 -> ... -> n - 1 -> 0`.
+    ///
+    /// Cycle of single vertex is a self-loop.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let graph = Graph::cycle(3, |v| v.to_string());
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::from([2])));
+    /// assert_eq!(graph.get_neighbours(2), Some(HashSet::from([0])));
+    /// ```
+    pub fn cycle(n: usize, value_fn: impl Fn(usize) -> T) -> Self {
+        let mut graph = Self::with_vertices(n, value_fn);
+        for v in 0..n {
+            graph.add_edge(v, (v + 1) % n).unwrap();
+        }
+        graph
+    }
+
+    /// Creates complete directed graph, each edge `u -> v` with `u != v` is present.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let graph = Graph::complete(3, |v| v.to_string());
+    /// assert_eq!(graph.get_neighbours(0), Some(HashSet::from([1, 2])));
+    /// assert_eq!(graph.get_neighbours(2), Some(HashSet::from([0, 1])));
+    /// ```
+    pub fn complete(n: usize, value_fn: impl Fn(usize) -> T) -> Self {
+        let mut graph = Self::with_vertices(n, value_fn);
+        for u in 0..n {
+            for v in 0..n {
+                if u != v {
+                    graph.add_edge(u, v).unwrap();
+                }
+            }
+        }
+        graph
+    }
+}
@@ -1,49 +1,511 @@
-use std::collections::{HashMap, HashSet};
-use std::fmt::{Debug, Display, Formatter};
-use std::io::{BufRead, BufReader, Read};
-use std::num::ParseIntError;
-use std::option::Option::Some;
-use std::str::FromStr;
+#[cfg(feature = "std")]
+use core::error::Error;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+#[cfg(feature = "std")]
+use core::num::{IntErrorKind, ParseIntError};
+use core::option::Option::Some;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::io::{BufRead, BufReader, Read, Write};
 
 use thiserror::Error;
 
-use crate::{BfsVisitor, GraphVertex, GraphVisitor};
+use crate::collections::hash_map;
+use crate::collections::{HashMap, HashSet};
+use crate::hash::{
+    from_public_map, from_public_set, into_public_map, into_public_set, remove_id, to_public_set, IdMap, IdSet,
+};
+use crate::parse_options::quote;
+#[cfg(feature = "std")]
+use crate::parse_options::{strip_comment, unquote};
+use crate::prelude::*;
+use crate::{BfsVisitor, DfsVisitor, GraphVertex, GraphVisitor};
+#[cfg(feature = "std")]
+use crate::{ParseOptions, ParseReport};
 
+/// Error of parsing vertex value, holds offending token, line number and error of value type.
+#[cfg(feature = "std")]
 #[derive(Error, Debug)]
-pub struct VertexValueParseError<E>(#[from] E);
+#[error("Fail to parse vertex value '{token}' on line {line}")]
+pub struct VertexValueParseError<E> {
+    token: String,
+    line: usize,
+    #[source]
+    source: E,
+}
+
+#[cfg(feature = "std")]
+impl<E> VertexValueParseError<E> {
+    pub(crate) fn new(token: &str, line: usize, source: E) -> Self {
+        Self {
+            token: token.to_string(),
+            line,
+            source,
+        }
+    }
+
+    /// Token which failed to parse as vertex value.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Number of line with the token, starting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Error returned by [`FromStr`] implementation of value type.
+    pub fn error(&self) -> &E {
+        &self.source
+    }
+
+    /// Consume error returning error of value type.
+    pub fn into_error(self) -> E {
+        self.source
+    }
+}
 
 #[derive(Error, Debug)]
-#[error("{message}")]
+#[error("Vertex {vertex} not exists in graph")]
 pub struct VertexNotExistsError {
-    message: String,
+    vertex: usize,
+}
+
+impl VertexNotExistsError {
+    pub(crate) fn new(vertex: usize) -> Self {
+        Self { vertex }
+    }
+
+    /// Id of missing vertex.
+    pub fn vertex(&self) -> usize {
+        self.vertex
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("Vertex {vertex} already exists in graph")]
+pub struct VertexExistsError {
+    vertex: usize,
+}
+
+impl VertexExistsError {
+    pub(crate) fn new(vertex: usize) -> Self {
+        Self { vertex }
+    }
+
+    /// Id of existing vertex.
+    pub fn vertex(&self) -> usize {
+        self.vertex
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("Edge {from} -> {to} not exists in graph")]
+pub struct EdgeNotExistsError {
+    from: usize,
+    to: usize,
+}
+
+impl EdgeNotExistsError {
+    pub(crate) fn new(from: usize, to: usize) -> Self {
+        Self { from, to }
+    }
+
+    /// Start and end of missing edge.
+    pub fn edge(&self) -> (usize, usize) {
+        (self.from, self.to)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum RenameError {
+    #[error(transparent)]
+    VertexNotExists(#[from] VertexNotExistsError),
+    #[error(transparent)]
+    VertexExists(#[from] VertexExistsError),
+}
+
+/// Error of parsing vertex number, holds offending token and line number.
+#[cfg(feature = "std")]
+#[derive(Error, Debug)]
+pub struct VertexIdParseError {
+    token: String,
+    line: usize,
+    #[source]
+    source: ParseIntError,
+}
+
+#[cfg(feature = "std")]
+impl VertexIdParseError {
+    pub(crate) fn new(token: &str, line: usize, source: ParseIntError) -> Self {
+        Self {
+            token: token.to_string(),
+            line,
+            source,
+        }
+    }
+
+    /// Token which failed to parse as vertex number.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Number of line with the token, starting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+#[cfg(feature = "std")]
+impl Display for VertexIdParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Fail to parse vertex number '{}' on line {}",
+            self.token, self.line
+        )?;
+        match self.source.kind() {
+            IntErrorKind::InvalidDigit if self.token.starts_with('-') => {
+                write!(f, ": negative numbers are not allowed")
+            }
+            IntErrorKind::PosOverflow => write!(f, ": number is too large"),
+            _ => write!(f, ": {}", self.source),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("Graph contains cycle {cycle:?}")]
+pub struct CycleError {
+    cycle: Vec<usize>,
+}
+
+impl CycleError {
+    pub(crate) fn new(cycle: Vec<usize>) -> Self {
+        Self { cycle }
+    }
+
+    /// Vertices of found cycle in order of edges between them.
+    pub fn cycle(&self) -> &[usize] {
+        &self.cycle
+    }
 }
 
+#[cfg(feature = "std")]
 #[derive(Error, Debug)]
 pub enum GraphParseError<E> {
     #[error(transparent)]
     IO(#[from] std::io::Error),
     #[error("Incorrect data, {0} items expected, {1} got")]
     DataError(usize, usize),
-    #[error("Fail to parse vertex number")]
-    VertexParseError(#[from] ParseIntError),
+    #[error(transparent)]
+    VertexParseError(#[from] VertexIdParseError),
     #[error(transparent)]
     ValueParseError(#[from] VertexValueParseError<E>),
     #[error(transparent)]
     VertexNotExists(#[from] VertexNotExistsError),
+    #[error("Vertex {0} is defined twice, line {1}")]
+    DuplicateVertex(usize, usize),
+    #[error("{0} on line {1}: '{2}'")]
+    EdgeVertexNotExists(#[source] VertexNotExistsError, usize, String),
+    #[error("Separator line repeated in edges section on line {0}")]
+    RepeatedSeparator(usize),
+    #[error("Quoted value is not properly closed on line {0}")]
+    QuotedValueError(usize),
+    #[error("Adjacency list line has no colon after vertex on line {0}")]
+    MissingColon(usize),
+}
+
+/// Error of parsing vertex value with its type erased, see [`GraphParseError::erase`].
+#[cfg(feature = "std")]
+#[derive(Error, Debug)]
+#[error(transparent)]
+pub struct ErasedValueError(Box<dyn Error + Send + Sync>);
+
+#[cfg(feature = "std")]
+impl ErasedValueError {
+    /// Get boxed error returned by [`FromStr`] implementation of value type.
+    pub fn into_inner(self) -> Box<dyn Error + Send + Sync> {
+        self.0
+    }
+}
+
+/// [`GraphParseError`] not depending on value type, convenient to propagate with `?`
+/// into errors which should not mention `T::Err`.
+#[cfg(feature = "std")]
+pub type DynGraphParseError = GraphParseError<ErasedValueError>;
+
+#[cfg(feature = "std")]
+impl<E: Error + Send + Sync + 'static> GraphParseError<E> {
+    /// Box error of value type, so result has the same type for graphs with different value types.
+    ///
+    /// ```
+    /// use std::num::ParseIntError;
+    /// use trivial_graph::{DynGraphParseError, Graph, GraphParseError};
+    /// fn count_vertices(data: &str) -> Result<usize, DynGraphParseError> {
+    ///     let ints: Graph<i32> = data.parse().map_err(GraphParseError::erase)?;
+    ///     let floats: Graph<f64> = data.parse().map_err(GraphParseError::erase)?;
+    ///     Ok(ints.get_vertices_ids().len() + floats.get_vertices_ids().len())
+    /// }
+    /// assert_eq!(count_vertices("1 10\n2 20\n#\n1 2\n").unwrap(), 4);
+    /// let err = count_vertices("1 x\n#\n").unwrap_err();
+    /// assert_eq!(err.to_string(), "Fail to parse vertex value 'x' on line 1");
+    /// if let GraphParseError::ValueParseError(err) = err {
+    ///     assert!(err.into_error().into_inner().downcast::<ParseIntError>().is_ok());
+    /// } else {
+    ///     assert!(false, "Incorrect error type");
+    /// }
+    /// ```
+    pub fn erase(self) -> DynGraphParseError {
+        match self {
+            GraphParseError::IO(err) => GraphParseError::IO(err),
+            GraphParseError::DataError(expected, got) => GraphParseError::DataError(expected, got),
+            GraphParseError::VertexParseError(err) => GraphParseError::VertexParseError(err),
+            GraphParseError::ValueParseError(err) => {
+                GraphParseError::ValueParseError(VertexValueParseError::new(
+                    &err.token,
+                    err.line,
+                    ErasedValueError(Box::new(err.source)),
+                ))
+            }
+            GraphParseError::VertexNotExists(err) => GraphParseError::VertexNotExists(err),
+            GraphParseError::DuplicateVertex(id, line) => {
+                GraphParseError::DuplicateVertex(id, line)
+            }
+            GraphParseError::EdgeVertexNotExists(err, line, token) => {
+                GraphParseError::EdgeVertexNotExists(err, line, token)
+            }
+            GraphParseError::RepeatedSeparator(line) => GraphParseError::RepeatedSeparator(line),
+            GraphParseError::QuotedValueError(line) => GraphParseError::QuotedValueError(line),
+            GraphParseError::MissingColon(line) => GraphParseError::MissingColon(line),
+        }
+    }
+}
+
+/// Violation of internal consistency of graph found by [`Graph::validate`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum GraphInvariantError {
+    #[error("Vertex stored by id {0} has id {1}")]
+    VertexIdMismatch(usize, usize),
+    #[error("Edge {0} -> {1} has endpoint absent in graph")]
+    DanglingEdge(usize, usize),
+    #[error("Predecessor index does not match edge {0} -> {1}")]
+    PredecessorIndexMismatch(usize, usize),
+    #[error("Label is stored for absent edge {0} -> {1}")]
+    LabelWithoutEdge(usize, usize),
 }
 
 #[derive(Debug, Default)]
 pub struct Graph<T: FromStr + Display> {
-    vertices: HashMap<usize, GraphVertex<T>>,
-    edges: HashMap<usize, HashSet<usize>>,
+    vertices: IdMap<GraphVertex<T>>,
+    edges: IdMap<IdSet>,
+    /// Reverse edges, present only if graph is created with predecessor index.
+    incoming: Option<IdMap<IdSet>>,
+    edge_labels: HashMap<(usize, usize), String>,
 }
 
 impl<T: FromStr + Display> Graph<T> {
+    /// Number of lines between calls of progress callback of [`Graph::from_reader_with_progress`].
+    #[cfg(feature = "std")]
+    pub const PROGRESS_LINES: usize = 1000;
+
     /// Creates empty graph.
     pub fn new() -> Self {
         Self {
-            vertices: HashMap::new(),
-            edges: HashMap::new(),
+            vertices: IdMap::default(),
+            edges: IdMap::default(),
+            incoming: None,
+            edge_labels: HashMap::new(),
+        }
+    }
+
+    /// Creates empty graph which additionally maintains index of incoming edges.
+    ///
+    /// Index makes [`Graph::get_predecessors`] cheap at cost of extra memory
+    /// and extra work on every edge modification.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::with_predecessor_index();
+    /// assert!(graph.has_predecessor_index());
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// graph.add_vertex(3, "node3".to_string());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert_eq!(graph.get_predecessors(3), Some(HashSet::from([1, 2])));
+    /// graph.remove_edge(1, 3);
+    /// assert_eq!(graph.get_predecessors(3), Some(HashSet::from([2])));
+    /// graph.remove_vertex(2);
+    /// assert_eq!(graph.get_predecessors(3), Some(HashSet::new()));
+    /// ```
+    pub fn with_predecessor_index() -> Self {
+        Self {
+            vertices: IdMap::default(),
+            edges: IdMap::default(),
+            incoming: Some(IdMap::default()),
+            edge_labels: HashMap::new(),
+        }
+    }
+
+    /// Check if graph maintains index of incoming edges, see [`Graph::with_predecessor_index`].
+    pub fn has_predecessor_index(&self) -> bool {
+        self.incoming.is_some()
+    }
+
+    /// Split graph into map of vertices by ids and map of neighbours sets by vertex ids.
+    ///
+    /// Vertices without outgoing edges may be absent in map of neighbours.
+    ///
+    /// Edge labels and predecessor index are discarded, and [`Graph::from_parts`] can't restore them:
+    /// set labels again with [`Graph::set_edge_label`] after rebuilding graph if they are needed.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.set_edge_label(1, 2, "uses".to_string()).is_ok());
+    /// let (vertices, mut edges) = graph.into_parts();
+    /// assert_eq!(vertices.len(), 2);
+    /// assert_eq!(edges[&1].iter().collect::<Vec<_>>(), vec![&2]);
+    ///
+    /// edges.entry(2).or_default().insert(1);
+    /// let graph = Graph::from_parts(vertices, edges).unwrap();
+    /// assert_eq!(graph.get_neighbours(2), Some(HashSet::from([1])));
+    /// assert_eq!(graph.edge_label(1, 2), None);
+    /// ```
+    pub fn into_parts(
+        self,
+    ) -> (
+        HashMap<usize, GraphVertex<T>>,
+        HashMap<usize, HashSet<usize>>,
+    ) {
+        let edges = self
+            .edges
+            .into_iter()
+            .map(|(v, neighbours)| (v, into_public_set(neighbours)))
+            .collect();
+        (into_public_map(self.vertices), edges)
+    }
+
+    /// Build graph from map of vertices by ids and map of neighbours sets by vertex ids,
+    /// reverse of [`Graph::into_parts`]. Created graph has no predecessor index and no edge labels.
+    ///
+    /// # Errors
+    /// Returns [`VertexNotExistsError`] if some edge starts or ends in vertex absent in `vertices`.
+    ///
+    /// ```
+    /// use trivial_graph::collections::{HashMap, HashSet};
+    /// use trivial_graph::{Graph, GraphVertex};
+    /// let vertices = HashMap::from([(1, GraphVertex::new(1, 10))]);
+    /// let edges = HashMap::from([(1, HashSet::from([2]))]);
+    /// assert!(Graph::from_parts(vertices, edges).is_err());
+    /// ```
+    pub fn from_parts(
+        vertices: HashMap<usize, GraphVertex<T>>,
+        edges: HashMap<usize, HashSet<usize>>,
+    ) -> Result<Self, VertexNotExistsError> {
+        for (v, neighbours) in &edges {
+            if let Some(missing) = core::iter::once(v)
+                .chain(neighbours)
+                .find(|&v| !vertices.contains_key(v))
+            {
+                return Err(VertexNotExistsError::new(*missing));
+            }
+        }
+        let edges = edges
+            .into_iter()
+            .filter(|(_, neighbours)| !neighbours.is_empty())
+            .map(|(v, neighbours)| (v, from_public_set(neighbours)))
+            .collect();
+        Ok(Self {
+            vertices: from_public_map(vertices),
+            edges,
+            incoming: None,
+            edge_labels: HashMap::new(),
+        })
+    }
+
+    /// Check internal consistency of graph: ids of vertices match keys they are stored by,
+    /// both ends of each edge exist, predecessor index (if any) matches edges
+    /// and edge labels belong to existing edges.
+    ///
+    /// Intended for debugging code which builds graph from parts, see [`Graph::from_parts`].
+    ///
+    /// # Errors
+    /// Returns [`GraphInvariantError`] describing the first found violation.
+    ///
+    /// ```
+    /// use trivial_graph::{Graph, GraphInvariantError, GraphVertex};
+    /// let mut graph = Graph::with_predecessor_index();
+    /// graph.add_vertex(1, 10);
+    /// graph.add_vertex(2, 20);
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.validate().is_ok());
+    ///
+    /// let (mut vertices, edges) = graph.into_parts();
+    /// vertices.insert(3, GraphVertex::new(4, 40));
+    /// let graph = Graph::from_parts(vertices, edges).unwrap();
+    /// assert_eq!(graph.validate(), Err(GraphInvariantError::VertexIdMismatch(3, 4)));
+    /// ```
+    pub fn validate(&self) -> Result<(), GraphInvariantError> {
+        if let Some((&key, v)) = self.vertices.iter().find(|(&key, v)| key != v.id) {
+            return Err(GraphInvariantError::VertexIdMismatch(key, v.id));
+        }
+        if let Some((v, u)) = self
+            .edges()
+            .find(|(v, u)| !self.vertices.contains_key(v) || !self.vertices.contains_key(u))
+        {
+            return Err(GraphInvariantError::DanglingEdge(v, u));
+        }
+        if let Some(incoming) = &self.incoming {
+            if let Some((v, u)) = self
+                .edges()
+                .find(|(v, u)| !incoming.get(u).is_some_and(|set| set.contains(v)))
+            {
+                return Err(GraphInvariantError::PredecessorIndexMismatch(v, u));
+            }
+            if let Some((u, v)) = incoming
+                .iter()
+                .flat_map(|(&u, set)| set.iter().map(move |&v| (u, v)))
+                .find(|&(u, v)| !self.has_edge(v, u))
+            {
+                return Err(GraphInvariantError::PredecessorIndexMismatch(v, u));
+            }
+        }
+        if let Some(&(v, u)) = self
+            .edge_labels
+            .keys()
+            .find(|(v, u)| !self.has_edge(*v, *u))
+        {
+            return Err(GraphInvariantError::LabelWithoutEdge(v, u));
+        }
+        Ok(())
+    }
+
+    /// Creates empty graph maintaining predecessor index if this graph does.
+    pub(crate) fn empty_like(&self) -> Self {
+        Self {
+            vertices: IdMap::default(),
+            edges: IdMap::default(),
+            incoming: self.incoming.as_ref().map(|_| IdMap::default()),
+            edge_labels: HashMap::new(),
+        }
+    }
+
+    /// Creates graph with copies of vertices of this graph and without edges.
+    pub(crate) fn clone_vertices(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            vertices: self.vertices.clone(),
+            ..self.empty_like()
         }
     }
 
@@ -52,7 +514,7 @@ impl<T: FromStr + Display> Graph<T> {
     /// If vertex with such identifier exists, replace value with new one.
     ///
     /// ```
-    /// use std::collections::HashSet;
+    /// use trivial_graph::collections::HashSet;
     /// use trivial_graph::Graph;
     /// let mut graph = Graph::new();
     /// graph.add_vertex(1, "node".to_string());
@@ -65,6 +527,32 @@ impl<T: FromStr + Display> Graph<T> {
             .insert(vertex, GraphVertex::new(vertex, value));
     }
 
+    /// Add new vertex to graph with given value if there is no vertex with such identifier.
+    ///
+    /// # Errors
+    /// Returns [`VertexExistsError`] if vertex already exists, graph is left unchanged.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// assert!(graph.try_add_vertex(1, "node".to_string()).is_ok());
+    /// let err = graph.try_add_vertex(1, "other".to_string()).unwrap_err();
+    /// assert_eq!(err.vertex(), 1);
+    /// assert_eq!(graph.get_vertex(1).unwrap().value, "node");
+    /// ```
+    pub fn try_add_vertex(&mut self, vertex: usize, value: T) -> Result<(), VertexExistsError> {
+        if self.vertices.contains_key(&vertex) {
+            return Err(VertexExistsError::new(vertex));
+        }
+        self.add_vertex(vertex, value);
+        Ok(())
+    }
+
+    /// Insert vertex as is, replacing vertex with the same id.
+    pub(crate) fn insert_vertex(&mut self, vertex: GraphVertex<T>) {
+        self.vertices.insert(vertex.id, vertex);
+    }
+
     /// Remove vertex from graph.
     ///
     /// If vertex does not exists, nothing happens.
@@ -78,16 +566,148 @@ impl<T: FromStr + Display> Graph<T> {
     /// graph.remove_vertex(1);
     /// assert_eq!(graph.get_vertices_ids().len(), 0);
     /// ```
+    ///
+    /// All edges from and to removed vertex are removed too.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// graph.add_vertex(3, "node3".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(3, 2).is_ok());
+    /// graph.remove_vertex(2);
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::new()));
+    /// assert_eq!(graph.get_neighbours(3), Some(HashSet::new()));
+    /// ```
     pub fn remove_vertex(&mut self, vertex: usize) {
-        let neighbours = self.edges.remove(&vertex);
-        if let Some(neighbours) = neighbours {
-            for neighbour in neighbours {
-                self.edges.get_mut(&neighbour).unwrap().remove(&vertex);
-            }
+        let neighbours = self.edges.get(&vertex).cloned().unwrap_or_default();
+        for neighbour in neighbours {
+            self.remove_edge(vertex, neighbour);
+        }
+        for predecessor in self.get_predecessors(vertex).unwrap_or_default() {
+            self.remove_edge(predecessor, vertex);
         }
         self.vertices.remove(&vertex);
     }
 
+    /// Change id of vertex from `old` to `new`, keeping its value, attributes,
+    /// incoming and outgoing edges and their labels.
+    ///
+    /// Renaming vertex to its own id does nothing.
+    ///
+    /// # Errors
+    /// Returns [`RenameError::VertexNotExists`] if `old` not in graph
+    /// and [`RenameError::VertexExists`] if `new` is already used by another vertex.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::{Graph, RenameError};
+    /// let mut graph = Graph::new();
+    /// for i in 1..=3 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(2, 2).is_ok());
+    /// assert!(graph.set_edge_label(1, 2, "in".to_string()).is_ok());
+    /// assert!(graph.rename_vertex(2, 5).is_ok());
+    /// assert_eq!(graph.get_vertices_ids(), HashSet::from([1, 3, 5]));
+    /// assert_eq!(graph.get_vertex(5).unwrap().id, 5);
+    /// assert_eq!(graph.get_vertex(5).unwrap().value, "2");
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::from([5])));
+    /// assert_eq!(graph.get_neighbours(5), Some(HashSet::from([3, 5])));
+    /// assert_eq!(graph.edge_label(1, 5), Some("in"));
+    ///
+    /// assert!(matches!(graph.rename_vertex(2, 6), Err(RenameError::VertexNotExists(_))));
+    /// assert!(matches!(graph.rename_vertex(5, 1), Err(RenameError::VertexExists(_))));
+    /// ```
+    pub fn rename_vertex(&mut self, old: usize, new: usize) -> Result<(), RenameError> {
+        if !self.vertices.contains_key(&old) {
+            return Err(VertexNotExistsError::new(old).into());
+        }
+        if old == new {
+            return Ok(());
+        }
+        if self.vertices.contains_key(&new) {
+            return Err(VertexExistsError::new(new).into());
+        }
+        let rename = |v: usize| if v == old { new } else { v };
+        let neighbours: Vec<_> = self.neighbours_iter(old).collect();
+        let predecessors = self.get_predecessors(old).unwrap_or_default();
+        let labels: Vec<_> = self
+            .edge_labels
+            .iter()
+            .filter(|((from, to), _)| *from == old || *to == old)
+            .map(|(&(from, to), label)| ((rename(from), rename(to)), label.clone()))
+            .collect();
+
+        let mut vertex = self.vertices.remove(&old).unwrap();
+        for &neighbour in &neighbours {
+            self.remove_edge(old, neighbour);
+        }
+        for &predecessor in &predecessors {
+            self.remove_edge(predecessor, old);
+        }
+        vertex.id = new;
+        self.vertices.insert(new, vertex);
+        for neighbour in neighbours {
+            self.add_edge(new, rename(neighbour)).unwrap();
+        }
+        for predecessor in predecessors {
+            self.add_edge(rename(predecessor), new).unwrap();
+        }
+        self.edge_labels.extend(labels);
+        Ok(())
+    }
+
+    /// Remove all vertices not satisfying `keep` together with their incoming and outgoing edges.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i * 10);
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(3, 4).is_ok());
+    /// assert!(graph.add_edge(4, 1).is_ok());
+    /// graph.retain_vertices(|v| v.value != 20);
+    /// assert_eq!(graph.get_vertices_ids(), HashSet::from([1, 3, 4]));
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::new()));
+    /// assert_eq!(graph.get_neighbours(3), Some(HashSet::from([4])));
+    /// assert_eq!(graph.get_predecessors(3), Some(HashSet::new()));
+    /// ```
+    pub fn retain_vertices<P: Fn(&GraphVertex<T>) -> bool>(&mut self, keep: P) {
+        let removed: HashSet<usize> = self
+            .vertices
+            .values()
+            .filter(|v| !keep(v))
+            .map(|v| v.id)
+            .collect();
+        if removed.is_empty() {
+            return;
+        }
+        let retain_index = |index: &mut IdMap<IdSet>| {
+            index.retain(|key, values| {
+                values.retain(|value| !removed.contains(value));
+                !removed.contains(key) && !values.is_empty()
+            });
+        };
+        retain_index(&mut self.edges);
+        if let Some(incoming) = &mut self.incoming {
+            retain_index(incoming);
+        }
+        self.edge_labels
+            .retain(|(from, to), _| !removed.contains(from) && !removed.contains(to));
+        self.vertices.retain(|id, _| !removed.contains(id));
+    }
+
     /// Add edge to current graph, both start and end of edge must exist in graph.
     ///
     /// # Errors
@@ -108,68 +728,307 @@ impl<T: FromStr + Display> Graph<T> {
         vertex_to: usize,
     ) -> Result<(), VertexNotExistsError> {
         if !self.vertices.contains_key(&vertex_from) {
-            return Err(VertexNotExistsError {
-                message: format!("Vertex {} not exists in graph", vertex_from),
-            });
+            return Err(VertexNotExistsError::new(vertex_from));
         }
         if !self.vertices.contains_key(&vertex_to) {
-            return Err(VertexNotExistsError {
-                message: format!("Vertex {} not exists in graph", vertex_to),
-            });
+            return Err(VertexNotExistsError::new(vertex_to));
         }
         self.edges.entry(vertex_from).or_default().insert(vertex_to);
+        if let Some(incoming) = &mut self.incoming {
+            incoming.entry(vertex_to).or_default().insert(vertex_from);
+        }
         Ok(())
     }
 
-    /// Remove edge from graph.
+    /// Add edge between vertices, never failing: missing ends are added to graph with value `T::default()`.
     ///
-    /// If edge not presented in graph, nothing happens.
+    /// Note: unlike [`Graph::add_edge`] changes set of vertices. Values of existing vertices are kept.
     ///
     /// ```
-    /// use std::collections::HashSet;
+    /// use trivial_graph::collections::HashSet;
     /// use trivial_graph::Graph;
     /// let mut graph = Graph::new();
     /// graph.add_vertex(1, "node".to_string());
-    /// graph.add_vertex(2, "node2".to_string());
-    /// assert!(graph.add_edge(1, 2).is_ok());
-    /// graph.remove_edge(1, 2);
-    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::new()));
+    /// graph.add_edge_auto(1, 2);
+    /// graph.add_edge_auto(3, 3);
+    /// assert_eq!(graph.get_vertices_ids(), HashSet::from([1, 2, 3]));
+    /// assert_eq!(graph.get_vertex(1).unwrap().value, "node");
+    /// assert_eq!(graph.get_vertex(2).unwrap().value, "");
+    /// assert!(graph.has_edge(1, 2));
+    /// assert!(graph.has_edge(3, 3));
     /// ```
-    pub fn remove_edge(&mut self, vertex_from: usize, vertex_to: usize) {
-        if let Some(from_neighbours) = self.edges.get_mut(&vertex_from) {
-            from_neighbours.remove(&vertex_to);
-            if from_neighbours.is_empty() {
-                self.edges.remove(&vertex_from);
+    pub fn add_edge_auto(&mut self, vertex_from: usize, vertex_to: usize)
+    where
+        T: Default,
+    {
+        for v in [vertex_from, vertex_to] {
+            if !self.vertices.contains_key(&v) {
+                self.add_vertex(v, T::default());
             }
         }
+        self.add_edge(vertex_from, vertex_to).unwrap();
     }
 
-    /// Get vertex from graph.
+    /// Replace all neighbours of `vertex` with given set, all vertices must exist in graph.
     ///
-    /// If vertex not presented in graph, returns `None`.
+    /// # Errors
+    /// Returns [`VertexNotExistsError`] if `vertex` or one of `neighbours` not in graph,
+    /// graph is left unchanged.
     ///
     /// ```
+    /// use trivial_graph::collections::HashSet;
     /// use trivial_graph::Graph;
     /// let mut graph = Graph::new();
     /// graph.add_vertex(1, "node".to_string());
-    /// let vertex = graph.get_vertex(1);
-    /// assert!(vertex.is_some());
-    /// let vertex = vertex.unwrap();
-    /// assert_eq!(vertex.id, 1);
-    /// assert_eq!(vertex.value, "node".to_string());
-    ///
-    /// assert!(graph.get_vertex(10).is_none());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// graph.add_vertex(3, "node3".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.set_neighbours(1, HashSet::from([1, 3])).is_ok());
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::from([1, 3])));
+    /// assert!(graph.set_neighbours(1, HashSet::from([2, 4])).is_err());
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::from([1, 3])));
+    /// assert!(graph.set_neighbours(4, HashSet::new()).is_err());
     /// ```
-    pub fn get_vertex(&self, vertex_id: usize) -> Option<&GraphVertex<T>> {
-        self.vertices.get(&vertex_id)
+    pub fn set_neighbours(
+        &mut self,
+        vertex: usize,
+        neighbours: HashSet<usize>,
+    ) -> Result<(), VertexNotExistsError> {
+        if let Some(missing) = core::iter::once(&vertex)
+            .chain(&neighbours)
+            .find(|&v| !self.vertices.contains_key(v))
+        {
+            return Err(VertexNotExistsError::new(*missing));
+        }
+        if let Some(incoming) = &mut self.incoming {
+            for old_neighbour in self.edges.get(&vertex).into_iter().flatten() {
+                Self::remove_from_index(incoming, *old_neighbour, vertex);
+            }
+            for &neighbour in &neighbours {
+                incoming.entry(neighbour).or_default().insert(vertex);
+            }
+        }
+        self.edge_labels
+            .retain(|&(from, to), _| from != vertex || neighbours.contains(&to));
+        if neighbours.is_empty() {
+            self.edges.remove(&vertex);
+        } else {
+            self.edges.insert(vertex, from_public_set(neighbours));
+        }
+        Ok(())
     }
 
-    /// Get set of neighbours of vertex in graph.
+    /// Remove edge from graph.
     ///
-    /// If vertex not presented in graph, returns `None`.
+    /// If edge not presented in graph, nothing happens.
     ///
     /// ```
-    /// use std::collections::HashSet;
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// graph.remove_edge(1, 2);
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::new()));
+    /// ```
+    pub fn remove_edge(&mut self, vertex_from: usize, vertex_to: usize) {
+        Self::remove_from_index(&mut self.edges, vertex_from, vertex_to);
+        self.edge_labels.remove(&(vertex_from, vertex_to));
+        if let Some(incoming) = &mut self.incoming {
+            Self::remove_from_index(incoming, vertex_to, vertex_from);
+        }
+    }
+
+    /// Set label of edge, replacing previous one.
+    ///
+    /// Label is removed together with edge.
+    ///
+    /// # Errors
+    /// Returns [`EdgeNotExistsError`] if edge not in graph.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.set_edge_label(1, 2, "depends_on".to_string()).is_ok());
+    /// assert!(graph.set_edge_label(2, 1, "depends_on".to_string()).is_err());
+    /// assert_eq!(graph.edge_label(1, 2), Some("depends_on"));
+    /// graph.remove_edge(1, 2);
+    /// assert_eq!(graph.edge_label(1, 2), None);
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert_eq!(graph.edge_label(1, 2), None);
+    /// ```
+    pub fn set_edge_label(
+        &mut self,
+        vertex_from: usize,
+        vertex_to: usize,
+        label: String,
+    ) -> Result<(), EdgeNotExistsError> {
+        if !self.has_edge(vertex_from, vertex_to) {
+            return Err(EdgeNotExistsError::new(vertex_from, vertex_to));
+        }
+        self.edge_labels.insert((vertex_from, vertex_to), label);
+        Ok(())
+    }
+
+    /// Get label of edge, `None` if edge has no label or not in graph.
+    pub fn edge_label(&self, vertex_from: usize, vertex_to: usize) -> Option<&str> {
+        self.edge_labels
+            .get(&(vertex_from, vertex_to))
+            .map(String::as_str)
+    }
+
+    /// Remove `value` from set stored by `key`, dropping set if it becomes empty.
+    fn remove_from_index(index: &mut IdMap<IdSet>, key: usize, value: usize) {
+        if let Some(values) = index.get_mut(&key) {
+            remove_id(values, value);
+            if values.is_empty() {
+                index.remove(&key);
+            }
+        }
+    }
+
+    /// Contract vertex `merge` into vertex `keep`.
+    ///
+    /// All edges from and to `merge` are redirected to `keep`, then `merge` is removed from graph.
+    /// Self-loops on `keep` appearing after redirection are dropped.
+    ///
+    /// Labels of redirected edges are moved with them. If `keep` already has the same edge,
+    /// its label is kept and label of redirected edge is used only if it has none.
+    ///
+    /// # Errors
+    /// Returns [`VertexNotExistsError`] if one of vertices not in graph, graph is left unchanged.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 1).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(4, 2).is_ok());
+    /// assert!(graph.set_edge_label(2, 3, "uses".to_string()).is_ok());
+    /// assert!(graph.set_edge_label(4, 2, "calls".to_string()).is_ok());
+    /// assert!(graph.contract_edge(1, 2).is_ok());
+    /// assert_eq!(graph.get_vertices_ids(), HashSet::from([1, 3, 4]));
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::from([3])));
+    /// assert_eq!(graph.get_neighbours(4), Some(HashSet::from([1])));
+    /// assert_eq!(graph.edge_label(1, 3), Some("uses"));
+    /// assert_eq!(graph.edge_label(4, 1), Some("calls"));
+    /// assert!(graph.contract_edge(1, 2).is_err());
+    /// ```
+    pub fn contract_edge(&mut self, keep: usize, merge: usize) -> Result<(), VertexNotExistsError> {
+        for vertex in [keep, merge] {
+            if !self.vertices.contains_key(&vertex) {
+                return Err(VertexNotExistsError::new(vertex));
+            }
+        }
+        if keep == merge {
+            return Ok(());
+        }
+        for neighbour in self.get_neighbours(merge).unwrap() {
+            let label = self.edge_labels.remove(&(merge, neighbour));
+            self.remove_edge(merge, neighbour);
+            if neighbour != keep && neighbour != merge {
+                self.add_edge(keep, neighbour).unwrap();
+                self.move_label((keep, neighbour), label);
+            }
+        }
+        for predecessor in self.get_predecessors(merge).unwrap() {
+            let label = self.edge_labels.remove(&(predecessor, merge));
+            self.remove_edge(predecessor, merge);
+            if predecessor != keep {
+                self.add_edge(predecessor, keep).unwrap();
+                self.move_label((predecessor, keep), label);
+            }
+        }
+        self.vertices.remove(&merge);
+        Ok(())
+    }
+
+    /// Set label of edge moved from other edge, keeping label already set on it.
+    fn move_label(&mut self, edge: (usize, usize), label: Option<String>) {
+        if let Some(label) = label {
+            self.edge_labels.entry(edge).or_insert(label);
+        }
+    }
+
+    /// Get vertex from graph.
+    ///
+    /// If vertex not presented in graph, returns `None`.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// let vertex = graph.get_vertex(1);
+    /// assert!(vertex.is_some());
+    /// let vertex = vertex.unwrap();
+    /// assert_eq!(vertex.id, 1);
+    /// assert_eq!(vertex.value, "node".to_string());
+    ///
+    /// assert!(graph.get_vertex(10).is_none());
+    /// ```
+    pub fn get_vertex(&self, vertex_id: usize) -> Option<&GraphVertex<T>> {
+        self.vertices.get(&vertex_id)
+    }
+
+    /// Replace value of existing vertex, returning previous value.
+    ///
+    /// Unlike [`Graph::add_vertex`], doesn't create vertex: if vertex not presented in graph, returns `None`.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// assert_eq!(graph.set_value(1, "renamed".to_string()), Some("node".to_string()));
+    /// assert_eq!(graph.get_vertex(1).unwrap().value, "renamed");
+    /// assert_eq!(graph.set_value(2, "node2".to_string()), None);
+    /// assert!(graph.get_vertex(2).is_none());
+    /// ```
+    pub fn set_value(&mut self, vertex_id: usize, value: T) -> Option<T> {
+        self.vertices
+            .get_mut(&vertex_id)
+            .map(|vertex| core::mem::replace(&mut vertex.value, value))
+    }
+
+    /// Set attribute of vertex in graph, see [`GraphVertex::set_attr`].
+    ///
+    /// # Errors
+    /// Returns [`VertexNotExistsError`] if vertex not in graph.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// assert!(graph.set_vertex_attr(1, "color", "red").is_ok());
+    /// assert_eq!(graph.get_vertex(1).unwrap().get_attr("color"), Some("red"));
+    /// assert!(graph.set_vertex_attr(2, "color", "red").is_err());
+    /// ```
+    pub fn set_vertex_attr(
+        &mut self,
+        vertex: usize,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Result<Option<String>, VertexNotExistsError> {
+        match self.vertices.get_mut(&vertex) {
+            Some(v) => Ok(v.set_attr(key, value)),
+            None => Err(VertexNotExistsError::new(vertex)),
+        }
+    }
+
+    /// Get set of neighbours of vertex in graph.
+    ///
+    /// If vertex not presented in graph, returns `None`.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
     /// use trivial_graph::Graph;
     /// let mut graph = Graph::new();
     /// graph.add_vertex(1, "node".to_string());
@@ -180,20 +1039,134 @@ impl<T: FromStr + Display> Graph<T> {
     /// ```
     pub fn get_neighbours(&self, vertex: usize) -> Option<HashSet<usize>> {
         if self.vertices.contains_key(&vertex) {
-            Some(
-                self.edges
-                    .get(&vertex)
-                    .map(Clone::clone)
-                    .unwrap_or_default(),
-            )
+            Some(self.edges.get(&vertex).map(to_public_set).unwrap_or_default())
         } else {
             None
         }
     }
+
+    /// Get neighbours of vertex in graph as vertices, ordered by ids.
+    ///
+    /// If vertex not presented in graph, returns `None`.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// graph.add_vertex(3, "node3".to_string());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// let values: Vec<_> = graph
+    ///     .neighbour_vertices(1)
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .map(|v| v.value.as_str())
+    ///     .collect();
+    /// assert_eq!(values, vec!["node2", "node3"]);
+    /// assert!(graph.neighbour_vertices(2).unwrap().is_empty());
+    /// assert!(graph.neighbour_vertices(4).is_none());
+    /// ```
+    pub fn neighbour_vertices(&self, vertex: usize) -> Option<Vec<&GraphVertex<T>>> {
+        if !self.vertices.contains_key(&vertex) {
+            return None;
+        }
+        let mut neighbours: Vec<_> = self
+            .neighbours_iter(vertex)
+            .map(|v| &self.vertices[&v])
+            .collect();
+        neighbours.sort_unstable_by_key(|v| v.id);
+        Some(neighbours)
+    }
+
+    /// Check if graph contains edge from `vertex_from` to `vertex_to`.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.has_edge(1, 2));
+    /// assert!(!graph.has_edge(2, 1));
+    /// assert!(!graph.has_edge(1, 3));
+    /// ```
+    pub fn has_edge(&self, vertex_from: usize, vertex_to: usize) -> bool {
+        self.edges
+            .get(&vertex_from)
+            .is_some_and(|neighbours| neighbours.contains(&vertex_to))
+    }
+
+    /// Get number of outgoing edges of vertex without copying set of neighbours.
+    ///
+    /// If vertex not presented in graph, returns `None`.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(1, 1).is_ok());
+    /// assert_eq!(graph.neighbour_count(1), Some(2));
+    /// assert_eq!(graph.neighbour_count(2), Some(0));
+    /// assert_eq!(graph.neighbour_count(3), None);
+    /// ```
+    pub fn neighbour_count(&self, vertex: usize) -> Option<usize> {
+        if !self.vertices.contains_key(&vertex) {
+            return None;
+        }
+        Some(
+            self.edges
+                .get(&vertex)
+                .map_or(0, |neighbours| neighbours.len()),
+        )
+    }
+
+    /// Get set of vertices having edge to given vertex.
+    ///
+    /// If vertex not presented in graph, returns `None`.
+    ///
+    /// Note: without predecessor index (see [`Graph::with_predecessor_index`]) scans all edges of graph.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// graph.add_vertex(3, "node3".to_string());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert_eq!(graph.get_predecessors(3), Some(HashSet::from([1, 2])));
+    /// assert_eq!(graph.get_predecessors(1), Some(HashSet::new()));
+    /// assert_eq!(graph.get_predecessors(4), None);
+    /// ```
+    pub fn get_predecessors(&self, vertex: usize) -> Option<HashSet<usize>> {
+        if !self.vertices.contains_key(&vertex) {
+            return None;
+        }
+        match &self.incoming {
+            Some(incoming) => Some(incoming.get(&vertex).map(to_public_set).unwrap_or_default()),
+            None => Some(
+                self.edges
+                    .iter()
+                    .filter(|(_, neighbours)| neighbours.contains(&vertex))
+                    .map(|(&v, _)| v)
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Iterate over neighbours of vertex without cloning adjacency set.
+    pub(crate) fn neighbours_iter(&self, vertex: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges.get(&vertex).into_iter().flatten().copied()
+    }
+
     /// Get set of vertices of graph.
     ///
     /// ```
-    /// use std::collections::HashSet;
+    /// use trivial_graph::collections::HashSet;
     /// use trivial_graph::Graph;
     /// let mut graph = Graph::new();
     /// graph.add_vertex(1, "node".to_string());
@@ -204,11 +1177,184 @@ impl<T: FromStr + Display> Graph<T> {
         self.vertices.keys().map(usize::clone).collect()
     }
 
+    /// Get the greatest id of vertices in graph, `None` if graph is empty.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// assert_eq!(graph.max_vertex_id(), None);
+    /// graph.add_vertex(3, "node".to_string());
+    /// graph.add_vertex(7, "node2".to_string());
+    /// graph.add_vertex(5, "node3".to_string());
+    /// assert_eq!(graph.max_vertex_id(), Some(7));
+    /// assert_eq!(graph.min_vertex_id(), Some(3));
+    /// ```
+    pub fn max_vertex_id(&self) -> Option<usize> {
+        self.vertices.keys().max().copied()
+    }
+
+    /// Get the least id of vertices in graph, `None` if graph is empty.
+    pub fn min_vertex_id(&self) -> Option<usize> {
+        self.vertices.keys().min().copied()
+    }
+
+    /// Get Jaccard index of sets of neighbours of two vertices.
+    ///
+    /// If both vertices have no neighbours, they are considered equal and similarity is `1.0`.
+    /// If one of vertices not presented in graph, returns `None`.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=5 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(1, 4).is_ok());
+    /// assert!(graph.add_edge(2, 4).is_ok());
+    /// assert!(graph.add_edge(2, 5).is_ok());
+    /// assert_eq!(graph.neighbour_similarity(1, 2), Some(1.0 / 3.0));
+    /// assert_eq!(graph.neighbour_similarity(1, 3), Some(0.0));
+    /// assert_eq!(graph.neighbour_similarity(3, 4), Some(1.0));
+    /// assert_eq!(graph.neighbour_similarity(1, 10), None);
+    /// ```
+    pub fn neighbour_similarity(&self, a: usize, b: usize) -> Option<f64> {
+        if !self.vertices.contains_key(&a) || !self.vertices.contains_key(&b) {
+            return None;
+        }
+        let empty = IdSet::default();
+        let a_neighbours = self.edges.get(&a).unwrap_or(&empty);
+        let b_neighbours = self.edges.get(&b).unwrap_or(&empty);
+        let union = a_neighbours.union(b_neighbours).count();
+        if union == 0 {
+            return Some(1.0);
+        }
+        let intersection = a_neighbours.intersection(b_neighbours).count();
+        Some(intersection as f64 / union as f64)
+    }
+
+    /// Get set of vertices which both `a` and `b` have edges to.
+    ///
+    /// If one of vertices not presented in graph, returns `None`.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=5 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(1, 4).is_ok());
+    /// assert!(graph.add_edge(2, 4).is_ok());
+    /// assert!(graph.add_edge(2, 5).is_ok());
+    /// assert_eq!(graph.common_successors(1, 2), Some(HashSet::from([4])));
+    /// assert_eq!(graph.common_successors(1, 3), Some(HashSet::new()));
+    /// assert_eq!(graph.common_successors(1, 10), None);
+    /// ```
+    pub fn common_successors(&self, a: usize, b: usize) -> Option<HashSet<usize>> {
+        if !self.vertices.contains_key(&a) || !self.vertices.contains_key(&b) {
+            return None;
+        }
+        let (Some(a_neighbours), Some(b_neighbours)) = (self.edges.get(&a), self.edges.get(&b))
+        else {
+            return Some(HashSet::new());
+        };
+        Some(a_neighbours.intersection(b_neighbours).copied().collect())
+    }
+
+    /// Get set of vertices which have edges to both `a` and `b`.
+    ///
+    /// If one of vertices not presented in graph, returns `None`.
+    ///
+    /// Note: without predecessor index (see [`Graph::with_predecessor_index`]) scans all edges of graph.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::with_predecessor_index();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(1, 4).is_ok());
+    /// assert!(graph.add_edge(2, 4).is_ok());
+    /// assert!(graph.add_edge(3, 4).is_ok());
+    /// assert_eq!(graph.common_predecessors(3, 4), Some(HashSet::from([1])));
+    /// assert_eq!(graph.common_predecessors(2, 4), Some(HashSet::new()));
+    /// assert_eq!(graph.common_predecessors(0, 4), None);
+    /// ```
+    pub fn common_predecessors(&self, a: usize, b: usize) -> Option<HashSet<usize>> {
+        if !self.vertices.contains_key(&a) || !self.vertices.contains_key(&b) {
+            return None;
+        }
+        match &self.incoming {
+            Some(incoming) => {
+                let (Some(a_predecessors), Some(b_predecessors)) =
+                    (incoming.get(&a), incoming.get(&b))
+                else {
+                    return Some(HashSet::new());
+                };
+                Some(
+                    a_predecessors
+                        .intersection(b_predecessors)
+                        .copied()
+                        .collect(),
+                )
+            }
+            None => Some(
+                self.edges
+                    .iter()
+                    .filter(|(_, neighbours)| neighbours.contains(&a) && neighbours.contains(&b))
+                    .map(|(&v, _)| v)
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Check if graphs have equal vertices and equal edges when direction of edges is ignored,
+    /// so edge `u -> v` matches edge `v -> u`.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// let mut other = Graph::new();
+    /// for i in 1..=3 {
+    ///     graph.add_vertex(i, i.to_string());
+    ///     other.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(other.add_edge(2, 1).is_ok());
+    /// assert!(other.add_edge(3, 2).is_ok());
+    /// assert!(other.add_edge(2, 3).is_ok());
+    /// assert!(graph.undirected_eq(&other));
+    ///
+    /// other.add_vertex(3, "three".to_string());
+    /// assert!(!graph.undirected_eq(&other));
+    /// ```
+    pub fn undirected_eq(&self, other: &Self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.vertices.len() == other.vertices.len()
+            && self
+                .vertices
+                .iter()
+                .all(|(id, v)| other.vertices.get(id).is_some_and(|u| u.value == v.value))
+            && self.undirected_edges() == other.undirected_edges()
+    }
+
+    /// Edges of graph without direction, each stored as `(min, max)` pair once.
+    pub(crate) fn undirected_edges(&self) -> HashSet<(usize, usize)> {
+        self.edges().map(|(v, u)| (v.min(u), v.max(u))).collect()
+    }
+
     /// Visit vertices in graph with `bfs` algorithm starting from `start_vertex` and apply `f` to them.
     ///
     /// In you want to visit all vertices in graph, see [`BfsVisitor`] and [`VisitOrder`] for more details.
     /// ```
-    /// use std::collections::HashSet;
+    /// use trivial_graph::collections::HashSet;
     /// use trivial_graph::Graph;
     /// let mut graph = Graph::new();
     /// graph.add_vertex(1, "node".to_string());
@@ -225,12 +1371,382 @@ impl<T: FromStr + Display> Graph<T> {
     /// assert_eq!(visited_vertices[0], 1);
     /// assert_eq!(visited_vertices[3], 4);
     /// ```
-    pub fn bfs<F: FnMut(&GraphVertex<T>)>(&self, start_vertex: usize, f: F) {
-        BfsVisitor::new(self).visit(start_vertex, f);
-    }
-
-    /// Reads graph from given reader and return `Graph` structure.
-    /// Requires value type to implement [`FromStr`] trait.
+    pub fn bfs<F: FnMut(&GraphVertex<T>)>(&self, start_vertex: usize, f: F) {
+        BfsVisitor::new(self).visit(start_vertex, f);
+    }
+
+    /// Get number of vertices reachable from `source`, including itself.
+    ///
+    /// If vertex not presented in graph, returns 0.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::path(4, |v| v.to_string());
+    /// graph.add_vertex(10, "10".to_string());
+    /// assert_eq!(graph.reachable_count(0), 4);
+    /// assert_eq!(graph.reachable_count(2), 2);
+    /// assert_eq!(graph.reachable_count(10), 1);
+    /// assert_eq!(graph.reachable_count(11), 0);
+    /// ```
+    pub fn reachable_count(&self, source: usize) -> usize {
+        let mut visitor = BfsVisitor::new(self);
+        visitor.visit(source, |_| {});
+        visitor.visited_count()
+    }
+
+    /// Get vertices not reachable from any of `roots`, e.g. dead code in dependency graph
+    /// with entry points as roots.
+    ///
+    /// Roots not presented in graph are ignored.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::path(3, |v| v.to_string());
+    /// graph.add_vertex(3, "3".to_string());
+    /// graph.add_vertex(4, "4".to_string());
+    /// assert!(graph.add_edge(4, 3).is_ok());
+    /// assert_eq!(graph.unreachable_from(&[1, 10]), HashSet::from([0, 3, 4]));
+    /// assert_eq!(graph.unreachable_from(&[0, 4]), HashSet::new());
+    /// assert_eq!(graph.unreachable_from(&[]).len(), 5);
+    /// ```
+    pub fn unreachable_from(&self, roots: &[usize]) -> HashSet<usize> {
+        let mut visitor = BfsVisitor::new(self);
+        for &root in roots {
+            visitor.visit(root, |_| {});
+        }
+        self.vertices
+            .keys()
+            .copied()
+            .filter(|&v| !visitor.is_visited(v))
+            .collect()
+    }
+
+    /// Consume graph returning vertices reachable from `start` in order of [`BfsVisitor`] traversal.
+    /// Unreachable vertices are dropped.
+    ///
+    /// If vertex not presented in graph, returns empty vector.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::path(4, |v| v.to_string());
+    /// graph.add_vertex(10, "10".to_string());
+    /// let values: Vec<_> = graph.into_bfs_order(1).into_iter().map(|v| v.value).collect();
+    /// assert_eq!(values, vec!["1", "2", "3"]);
+    /// ```
+    pub fn into_bfs_order(self, start: usize) -> Vec<GraphVertex<T>> {
+        let order = BfsVisitor::new(&self).collect_from(start);
+        self.take_vertices(order)
+    }
+
+    /// Consume graph returning vertices reachable from `start` in order of [`DfsVisitor`] traversal.
+    /// Unreachable vertices are dropped.
+    ///
+    /// If vertex not presented in graph, returns empty vector.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i * 10);
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// let vertices = graph.into_dfs_order(1);
+    /// assert_eq!(vertices.iter().map(|v| v.value).collect::<Vec<_>>(), vec![10, 20, 30]);
+    ///
+    /// let vertices = Graph::path(100_000, |v| v).into_dfs_order(0);
+    /// assert_eq!(vertices.len(), 100_000);
+    /// assert_eq!(vertices[99_999].value, 99_999);
+    /// ```
+    pub fn into_dfs_order(self, start: usize) -> Vec<GraphVertex<T>> {
+        let order = DfsVisitor::new(&self).collect_from(start);
+        self.take_vertices(order)
+    }
+
+    /// Consume graph returning vertices with given ids in given order.
+    fn take_vertices(mut self, order: Vec<usize>) -> Vec<GraphVertex<T>> {
+        order
+            .into_iter()
+            .map(|v| self.vertices.remove(&v).unwrap())
+            .collect()
+    }
+
+    /// Get human-readable listing of neighbours of each vertex like `1 -> {2, 3}`, one vertex per line.
+    ///
+    /// Vertices and neighbours are sorted ascending. Intended for debugging, use [`Display`] for serialization.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// graph.add_vertex(3, "node3".to_string());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(3, 1).is_ok());
+    /// assert_eq!(graph.adjacency_list_string(), "1 -> {2, 3}\n2 -> {}\n3 -> {1}\n");
+    /// ```
+    pub fn adjacency_list_string(&self) -> String {
+        let mut vertices: Vec<_> = self.vertices.keys().copied().collect();
+        vertices.sort_unstable();
+        let mut result = String::new();
+        for v in vertices {
+            let mut neighbours: Vec<_> = self.neighbours_iter(v).collect();
+            neighbours.sort_unstable();
+            let neighbours: Vec<_> = neighbours.iter().map(usize::to_string).collect();
+            result.push_str(&format!("{} -> {{{}}}\n", v, neighbours.join(", ")));
+        }
+        result
+    }
+
+    /// Iterate over edges of graph as `(from, to)` pairs in arbitrary order.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 2).is_ok());
+    /// assert_eq!(graph.edges().count(), 2);
+    /// assert!(graph.edges().any(|edge| edge == (2, 2)));
+    /// ```
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.edges
+            .iter()
+            .flat_map(|(&v, neighbours)| neighbours.iter().map(move |&u| (v, u)))
+    }
+
+    /// Get edges of graph sorted by start vertex, then by end vertex.
+    ///
+    /// Collects and sorts all edges, so takes `O(E log E)` time, use [`Graph::edges`] if order doesn't matter.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// for i in 1..=3 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(3, 1).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert_eq!(graph.edges_sorted(), vec![(1, 2), (1, 3), (3, 1)]);
+    /// ```
+    pub fn edges_sorted(&self) -> Vec<(usize, usize)> {
+        let mut edges: Vec<_> = self.edges().collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    /// Writes edges of graph in CSV format: header `from,to` followed by one row per edge.
+    ///
+    /// Only structure of graph is written, vertex values and edge labels are not exported.
+    /// Rows are ordered by start vertex, then by end vertex.
+    ///
+    /// # Errors
+    /// Returns I/O errors of writer.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// graph.add_vertex(3, "node3".to_string());
+    /// assert!(graph.add_edge(2, 1).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.set_edge_label(1, 3, "uses".to_string()).is_ok());
+    /// let mut csv = Vec::new();
+    /// assert!(graph.to_csv(&mut csv).is_ok());
+    /// assert_eq!(String::from_utf8(csv).unwrap(), "from,to\n1,2\n1,3\n2,1\n");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_csv<W: Write>(&self, w: &mut W) -> std::io::Result<()> {
+        writeln!(w, "from,to")?;
+        for (v, u) in self.edges_sorted() {
+            writeln!(w, "{},{}", v, u)?;
+        }
+        Ok(())
+    }
+
+    /// Get graph in Graphviz DOT language: `digraph` with a node statement for each vertex
+    /// and an edge statement for each edge.
+    ///
+    /// Value of vertex is written as its `label`, attributes of vertex (see [`GraphVertex::set_attr`])
+    /// are written as node attributes, so attribute `label` replaces value.
+    /// Edge labels (see [`Graph::set_edge_label`]) are written as `label` of edges.
+    /// Vertices, attributes and edges are sorted ascending.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(2, "say \"hi\"".to_string());
+    /// graph.add_vertex(1, "node".to_string());
+    /// assert!(graph.set_vertex_attr(1, "shape", "box").is_ok());
+    /// assert!(graph.set_vertex_attr(1, "color", "red").is_ok());
+    /// assert!(graph.add_edge(2, 1).is_ok());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.set_edge_label(2, 1, "depends_on".to_string()).is_ok());
+    /// assert_eq!(
+    ///     graph.to_dot(),
+    ///     concat!(
+    ///         "digraph {\n",
+    ///         "    1 [label=\"node\", color=\"red\", shape=\"box\"];\n",
+    ///         "    2 [label=\"say \\\"hi\\\"\"];\n",
+    ///         "    1 -> 2;\n",
+    ///         "    2 -> 1 [label=\"depends_on\"];\n",
+    ///         "}\n"
+    ///     )
+    /// );
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let dot_id = |s: &str| {
+            let is_plain = s.chars().next().is_some_and(|c| !c.is_ascii_digit())
+                && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if is_plain {
+                s.to_string()
+            } else {
+                quote(s)
+            }
+        };
+        let mut vertices: Vec<_> = self.vertices.values().collect();
+        vertices.sort_unstable_by_key(|v| v.id);
+        let mut result = String::from("digraph {\n");
+        for v in vertices {
+            let mut attrs: Vec<_> = v
+                .attrs()
+                .iter()
+                .map(|(k, v)| (k.as_str(), quote(v)))
+                .collect();
+            attrs.sort_unstable();
+            if !v.attrs().contains_key("label") {
+                attrs.insert(0, ("label", quote(&v.value.to_string())));
+            }
+            let attrs: Vec<_> = attrs
+                .into_iter()
+                .map(|(key, value)| format!("{}={}", dot_id(key), value))
+                .collect();
+            result.push_str(&format!("    {} [{}];\n", v.id, attrs.join(", ")));
+        }
+        for (v, u) in self.edges_sorted() {
+            match self.edge_label(v, u) {
+                Some(label) => {
+                    result.push_str(&format!("    {} -> {} [label={}];\n", v, u, quote(label)))
+                }
+                None => result.push_str(&format!("    {} -> {};\n", v, u)),
+            }
+        }
+        result.push_str("}\n");
+        result
+    }
+
+    /// Get diagram of graph in Mermaid syntax: `graph TD` header, then `id["value"]` line
+    /// for each vertex and `from --> to` line for each edge.
+    ///
+    /// Edges with label are written as `from -->|"label"| to`.
+    /// Vertices and edges are sorted ascending. Double quotes in values and labels are written as `#quot;`.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(2, "say \"hi\"".to_string());
+    /// graph.add_vertex(1, "node".to_string());
+    /// assert!(graph.add_edge(2, 1).is_ok());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert_eq!(
+    ///     graph.to_mermaid(),
+    ///     "graph TD\n    1[\"node\"]\n    2[\"say #quot;hi#quot;\"]\n    1 --> 2\n    2 --> 1\n"
+    /// );
+    /// assert!(graph.set_edge_label(2, 1, "replies".to_string()).is_ok());
+    /// assert!(graph.to_mermaid().ends_with("    2 -->|\"replies\"| 1\n"));
+    /// ```
+    pub fn to_mermaid(&self) -> String {
+        let mut vertices: Vec<_> = self.vertices.values().collect();
+        vertices.sort_unstable_by_key(|v| v.id);
+        let mut result = String::from("graph TD\n");
+        for v in vertices {
+            let label = v.value.to_string().replace('"', "#quot;");
+            result.push_str(&format!("    {}[\"{}\"]\n", v.id, label));
+        }
+        for (v, u) in self.edges_sorted() {
+            match self.edge_label(v, u) {
+                Some(label) => result.push_str(&format!(
+                    "    {} -->|\"{}\"| {}\n",
+                    v,
+                    label.replace('"', "#quot;"),
+                    u
+                )),
+                None => result.push_str(&format!("    {} --> {}\n", v, u)),
+            }
+        }
+        result
+    }
+
+    /// Get canonical text form of acyclic graph in the same format as [`Display`]: vertices
+    /// in topological order, ties broken by ascending id, then `#` line and edges sorted ascending.
+    ///
+    /// Equal graphs produce equal strings, so output is suitable for diffs under version control.
+    ///
+    /// # Errors
+    /// Returns [`CycleError`] if graph contains cycle.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "leaf".to_string());
+    /// graph.add_vertex(3, "root".to_string());
+    /// graph.add_vertex(2, "middle".to_string());
+    /// assert!(graph.add_edge(3, 1).is_ok());
+    /// assert!(graph.add_edge(3, 2).is_ok());
+    /// assert!(graph.add_edge(2, 1).is_ok());
+    /// assert_eq!(
+    ///     graph.to_string_topological().unwrap(),
+    ///     "3 root\n2 middle\n1 leaf\n#\n2 1\n3 1\n3 2\n"
+    /// );
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.to_string_topological().is_err());
+    /// ```
+    pub fn to_string_topological(&self) -> Result<String, CycleError> {
+        let order = self.topological_sort_by(|a, b| a.cmp(&b))?;
+        let mut result = String::new();
+        for v in order {
+            result.push_str(&format!("{} {}\n", v, self.vertices[&v].value));
+        }
+        result.push_str("#\n");
+        for (v, u) in self.edges_sorted() {
+            result.push_str(&format!("{} {}\n", v, u));
+        }
+        Ok(result)
+    }
+
+    /// Reads graph from given reader and return `Graph` structure.
+    /// Requires value type to implement [`FromStr`] trait.
+    ///
+    /// Each line is trimmed, vertex value is parsed from the rest of the line after the first space.
+    /// To remove whitespace left around value, see [`ParseOptions::trim_values`].
+    ///
+    /// Empty lines between vertices are skipped, `#` line ends vertices section.
+    /// Empty line or end of data ends edges section.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph_string = concat!(
+    /// "1 1\n",
+    /// "\n",
+    /// "2 2\n",
+    /// "\n",
+    /// "#\n",
+    /// "1 2\n",
+    /// "\n",
+    /// "2 1\n"
+    /// ).as_bytes();
+    /// let graph = Graph::<i32>::from_reader(&mut graph_string).unwrap();
+    /// assert_eq!(graph.get_vertices_ids(), HashSet::from([1, 2]));
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::from([2])));
+    /// assert_eq!(graph.get_neighbours(2), Some(HashSet::new()));
+    /// ```
     ///
     ///
     /// # Errors
@@ -238,7 +1754,7 @@ impl<T: FromStr + Display> Graph<T> {
     /// See [`GraphParseError`] documentation for more details.
     ///
     /// ```
-    /// use std::collections::HashSet;
+    /// use trivial_graph::collections::HashSet;
     /// use trivial_graph::Graph;
     /// let mut graph_string = concat!(
     /// "1 1\n",
@@ -274,6 +1790,25 @@ impl<T: FromStr + Display> Graph<T> {
     /// ```
     ///
     /// ```
+    /// use std::num::{IntErrorKind, ParseIntError};
+    /// use trivial_graph::{Graph, GraphParseError};
+    /// let mut graph_string = concat!(
+    /// "1 1\n",
+    /// "2 4294967296\n",
+    /// "#\n"
+    /// ).as_bytes();
+    /// let err = Graph::<u32>::from_reader(&mut graph_string).unwrap_err();
+    /// assert_eq!(err.to_string(), "Fail to parse vertex value '4294967296' on line 2");
+    /// if let GraphParseError::<ParseIntError>::ValueParseError(e) = err {
+    ///     assert_eq!(e.token(), "4294967296");
+    ///     assert_eq!(e.line(), 2);
+    ///     assert_eq!(e.error().kind(), &IntErrorKind::PosOverflow);
+    /// } else {
+    ///     assert!(false, "Incorrect error type");
+    /// }
+    /// ```
+    ///
+    /// ```
     /// use std::num::ParseIntError;
     /// use trivial_graph::{Graph, GraphParseError};
     /// let mut graph_string = concat!(
@@ -297,6 +1832,27 @@ impl<T: FromStr + Display> Graph<T> {
     /// use trivial_graph::{Graph, GraphParseError};
     /// let mut graph_string = concat!(
     /// "1 1\n",
+    /// "-2 2\n",
+    /// "#\n"
+    /// ).as_bytes();
+    /// let err = Graph::<i32>::from_reader(&mut graph_string).unwrap_err();
+    /// if let GraphParseError::<ParseIntError>::VertexParseError(e) = err {
+    ///     assert_eq!(e.token(), "-2");
+    ///     assert_eq!(e.line(), 2);
+    ///     assert_eq!(
+    ///         e.to_string(),
+    ///         "Fail to parse vertex number '-2' on line 2: negative numbers are not allowed"
+    ///     );
+    /// } else {
+    ///     assert!(false, "Incorrect error type");
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use std::num::ParseIntError;
+    /// use trivial_graph::{Graph, GraphParseError};
+    /// let mut graph_string = concat!(
+    /// "1 1\n",
     /// "2 2\n",
     /// "#\n",
     /// "1\n"
@@ -310,52 +1866,680 @@ impl<T: FromStr + Display> Graph<T> {
     ///     assert!(false, "Incorrect error type");
     /// }
     /// ```
+    ///
+    /// ```
+    /// use std::num::ParseIntError;
+    /// use trivial_graph::{Graph, GraphParseError};
+    /// let mut graph_string = concat!(
+    /// "1 1\n",
+    /// "2 2\n",
+    /// "#\n",
+    /// "1 2\n",
+    /// "2 3\n"
+    /// ).as_bytes();
+    /// let err = Graph::<i32>::from_reader(&mut graph_string).unwrap_err();
+    /// if let GraphParseError::<ParseIntError>::EdgeVertexNotExists(e, line, content) = err {
+    ///     assert_eq!(e.vertex(), 3);
+    ///     assert_eq!(line, 5);
+    ///     assert_eq!(content, "2 3");
+    /// } else {
+    ///     assert!(false, "Incorrect error type");
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use std::num::ParseIntError;
+    /// use trivial_graph::{Graph, GraphParseError};
+    /// let mut graph_string = concat!(
+    /// "1 1\n",
+    /// "#\n",
+    /// "#\n",
+    /// "1 1\n"
+    /// ).as_bytes();
+    /// let err = Graph::<i32>::from_reader(&mut graph_string).unwrap_err();
+    /// if let GraphParseError::<ParseIntError>::RepeatedSeparator(line) = err {
+    ///     assert_eq!(line, 3);
+    /// } else {
+    ///     assert!(false, "Incorrect error type");
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
     pub fn from_reader<R: Read>(reader: &mut R) -> Result<Self, GraphParseError<T::Err>> {
-        let mut graph = Self {
-            vertices: Default::default(),
-            edges: Default::default(),
-        };
+        Self::from_reader_buffered(&mut BufReader::new(reader))
+    }
+
+    /// Reads graph from given buffered reader like [`Graph::from_reader`], without wrapping it into another buffer.
+    ///
+    /// Reader is left right after the data of the graph.
+    ///
+    /// # Errors
+    /// Return `GraphParseError` in case of some I/O or parsing problems.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use std::io::{BufRead, BufReader};
+    /// use trivial_graph::Graph;
+    /// let graph_string = concat!(
+    /// "1 1\n",
+    /// "2 2\n",
+    /// "#\n",
+    /// "1 2\n",
+    /// "\n",
+    /// "rest\n"
+    /// );
+    /// let mut reader = BufReader::new(graph_string.as_bytes());
+    /// let graph = Graph::<i32>::from_reader_buffered(&mut reader).unwrap();
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::from([2])));
+    /// let mut rest = String::new();
+    /// reader.read_line(&mut rest).unwrap();
+    /// assert_eq!(rest, "rest\n");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_reader_buffered<R: BufRead>(
+        reader: &mut R,
+    ) -> Result<Self, GraphParseError<T::Err>> {
+        Self::read_graph(
+            reader,
+            &ParseOptions::default(),
+            &mut 0,
+            &mut ParseReport::default(),
+            &mut |_| {},
+        )
+    }
+
+    /// Reads graph from string like [`Graph::from_reader`].
+    ///
+    /// # Errors
+    /// Return `GraphParseError` in case of parsing problems.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let graph = Graph::<i32>::from_str_graph("1 10\n2 20\n#\n1 2\n").unwrap();
+    /// assert_eq!(graph.get_vertex(2).unwrap().value, 20);
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::from([2])));
+    /// assert!(Graph::<i32>::from_str_graph("1 a\n").is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_str_graph(s: &str) -> Result<Self, GraphParseError<T::Err>> {
+        Self::from_reader(&mut s.as_bytes())
+    }
+
+    /// Reads graph from given reader like [`Graph::from_reader`], but with parsing behaviour
+    /// configured by `options`. See [`ParseOptions`] for available settings.
+    ///
+    /// # Errors
+    /// Return `GraphParseError` in case of some I/O or parsing problems.
+    /// With [`ParseOptions::reject_duplicate_vertices`] set, returns
+    /// [`GraphParseError::DuplicateVertex`] if some vertex is defined twice.
+    ///
+    /// ```
+    /// use std::num::ParseIntError;
+    /// use trivial_graph::{Graph, GraphParseError, ParseOptions};
+    /// let graph_string = concat!(
+    /// "1 1\n",
+    /// "2 2\n",
+    /// "1 3\n",
+    /// "#\n",
+    /// "1 2\n"
+    /// );
+    /// let graph = Graph::<i32>::from_reader(&mut graph_string.as_bytes()).unwrap();
+    /// assert_eq!(graph.get_vertex(1).unwrap().value, 3);
+    ///
+    /// let options = ParseOptions {
+    ///     reject_duplicate_vertices: true,
+    ///     ..Default::default()
+    /// };
+    /// let res = Graph::<i32>::from_reader_with_options(&mut graph_string.as_bytes(), &options);
+    /// if let Err(GraphParseError::<ParseIntError>::DuplicateVertex(id, line)) = res {
+    ///     assert_eq!(id, 1);
+    ///     assert_eq!(line, 3);
+    /// } else {
+    ///     assert!(false, "Incorrect error type");
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::{Graph, ParseOptions};
+    /// let mut graph_string = concat!(
+    /// "1;one\n",
+    /// "2;two\n",
+    /// "---\n",
+    /// "1;2\n"
+    /// ).as_bytes();
+    /// let options = ParseOptions {
+    ///     separator: "---".to_string(),
+    ///     delimiter: ';',
+    ///     ..Default::default()
+    /// };
+    /// let graph = Graph::<String>::from_reader_with_options(&mut graph_string, &options).unwrap();
+    /// assert_eq!(graph.get_vertex(2).unwrap().value, "two");
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::from([2])));
+    /// ```
+    ///
+    /// Tab- and comma-separated files: value of vertex is everything after the first delimiter.
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::{Graph, ParseOptions};
+    /// for (delimiter, graph_string) in [
+    ///     ('\t', "1\tone two\n2\tthree\tfour\n#\n1\t2\n2\t2\n"),
+    ///     (',', "1,one two\n2,three,four\n#\n1,2\n2,2\n"),
+    /// ] {
+    ///     let options = ParseOptions {
+    ///         delimiter,
+    ///         ..Default::default()
+    ///     };
+    ///     let graph =
+    ///         Graph::<String>::from_reader_with_options(&mut graph_string.as_bytes(), &options).unwrap();
+    ///     assert_eq!(graph.get_vertex(1).unwrap().value, "one two");
+    ///     assert_eq!(
+    ///         graph.get_vertex(2).unwrap().value,
+    ///         format!("three{}four", delimiter)
+    ///     );
+    ///     assert_eq!(graph.get_neighbours(1), Some(HashSet::from([2])));
+    ///     assert_eq!(graph.get_neighbours(2), Some(HashSet::from([2])));
+    ///     assert!(Graph::<String>::from_reader(&mut graph_string.as_bytes()).is_err());
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use trivial_graph::{Graph, ParseOptions};
+    /// #[derive(Debug, PartialEq)]
+    /// enum Color {
+    ///     Red,
+    ///     Green,
+    /// }
+    /// impl FromStr for Color {
+    ///     type Err = String;
+    ///     fn from_str(s: &str) -> Result<Self, Self::Err> {
+    ///         match s {
+    ///             "red" => Ok(Color::Red),
+    ///             "green" => Ok(Color::Green),
+    ///             _ => Err(format!("unknown color '{}'", s)),
+    ///         }
+    ///     }
+    /// }
+    /// impl std::fmt::Display for Color {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         write!(f, "{:?}", self)
+    ///     }
+    /// }
+    /// let graph_string = "1   red\n2 green\n#\n";
+    /// assert!(Graph::<Color>::from_reader(&mut graph_string.as_bytes()).is_err());
+    /// let options = ParseOptions {
+    ///     trim_values: true,
+    ///     ..Default::default()
+    /// };
+    /// let graph = Graph::<Color>::from_reader_with_options(&mut graph_string.as_bytes(), &options).unwrap();
+    /// assert_eq!(graph.get_vertex(1).unwrap().value, Color::Red);
+    /// ```
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::{Graph, ParseOptions};
+    /// let mut graph_string = concat!(
+    /// "# services\n",
+    /// "1 api # public\n",
+    /// "2 db#1\n",
+    /// "#\n",
+    /// "1 2 # main dependency\n",
+    /// "# 2 1\n",
+    /// "2 2\n"
+    /// ).as_bytes();
+    /// let options = ParseOptions {
+    ///     inline_comments: true,
+    ///     ..Default::default()
+    /// };
+    /// let graph = Graph::<String>::from_reader_with_options(&mut graph_string, &options).unwrap();
+    /// assert_eq!(graph.get_vertex(1).unwrap().value, "api");
+    /// assert_eq!(graph.get_vertex(2).unwrap().value, "db#1");
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::from([2])));
+    /// assert_eq!(graph.get_neighbours(2), Some(HashSet::from([2])));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_reader_with_options<R: Read>(
+        reader: &mut R,
+        options: &ParseOptions,
+    ) -> Result<Self, GraphParseError<T::Err>> {
+        let mut buf_reader = BufReader::new(reader);
+        Self::read_graph(
+            &mut buf_reader,
+            options,
+            &mut 0,
+            &mut ParseReport::default(),
+            &mut |_| {},
+        )
+    }
+
+    /// Writes graph in format read by [`Graph::from_reader_with_options`] with the same `options`:
+    /// vertex lines sorted by id, separator line and edge lines sorted by start, then by end vertex.
+    ///
+    /// With [`ParseOptions::quoted_values`] values which are empty, contain whitespace or delimiter,
+    /// or start with double quote are written quoted.
+    ///
+    /// # Errors
+    /// Returns I/O errors of writer.
+    ///
+    /// ```
+    /// use trivial_graph::{Graph, ParseOptions};
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "New York".to_string());
+    /// graph.add_vertex(2, "say \"hi\"".to_string());
+    /// graph.add_vertex(3, "Paris".to_string());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// let options = ParseOptions {
+    ///     quoted_values: true,
+    ///     ..Default::default()
+    /// };
+    /// let mut output = Vec::new();
+    /// assert!(graph.write_with_options(&mut output, &options).is_ok());
+    /// assert_eq!(
+    ///     String::from_utf8(output.clone()).unwrap(),
+    ///     "1 \"New York\"\n2 \"say \\\"hi\\\"\"\n3 Paris\n#\n1 3\n"
+    /// );
+    /// let parsed = Graph::<String>::from_reader_with_options(&mut output.as_slice(), &options).unwrap();
+    /// assert_eq!(parsed, graph);
+    ///
+    /// let mut graph_string = "1 \"New York\" city\n#\n".as_bytes();
+    /// assert!(Graph::<String>::from_reader_with_options(&mut graph_string, &options).is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_with_options<W: Write>(
+        &self,
+        w: &mut W,
+        options: &ParseOptions,
+    ) -> std::io::Result<()> {
+        let mut vertices: Vec<_> = self.vertices.values().collect();
+        vertices.sort_unstable_by_key(|v| v.id);
+        let delimiter = options.delimiter;
+        for v in vertices {
+            let value = v.value.to_string();
+            let needs_quotes = value.is_empty()
+                || value.starts_with('"')
+                || value.contains(|c: char| c == delimiter || c.is_whitespace());
+            if options.quoted_values && needs_quotes {
+                writeln!(w, "{}{}{}", v.id, delimiter, quote(&value))?;
+            } else {
+                writeln!(w, "{}{}{}", v.id, delimiter, value)?;
+            }
+        }
+        writeln!(w, "{}", options.separator)?;
+        for (v, u) in self.edges_sorted() {
+            writeln!(w, "{}{}{}", v, delimiter, u)?;
+        }
+        Ok(())
+    }
+
+    /// Reads graph from given reader like [`Graph::from_reader_with_options`],
+    /// additionally returning [`ParseReport`] with statistics of input.
+    ///
+    /// # Errors
+    /// Return `GraphParseError` in case of some I/O or parsing problems.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::{Graph, ParseOptions};
+    /// let mut graph_string = concat!(
+    /// "1 1\n",
+    /// "2 2\n",
+    /// "#\n",
+    /// "1 2\n",
+    /// "2 1\n",
+    /// "1 2\n"
+    /// ).as_bytes();
+    /// let (graph, report) =
+    ///     Graph::<i32>::from_reader_with_report(&mut graph_string, &ParseOptions::default()).unwrap();
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::from([2])));
+    /// assert_eq!(report.duplicate_edges, 1);
+    /// assert_eq!(report.duplicate_vertices, 0);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_reader_with_report<R: Read>(
+        reader: &mut R,
+        options: &ParseOptions,
+    ) -> Result<(Self, ParseReport), GraphParseError<T::Err>> {
+        let mut buf_reader = BufReader::new(reader);
+        let mut report = ParseReport::default();
+        let graph = Self::read_graph(&mut buf_reader, options, &mut 0, &mut report, &mut |_| {})?;
+        Ok((graph, report))
+    }
+
+    /// Reads graph from given reader like [`Graph::from_reader`], calling `progress` with number
+    /// of lines read so far after each [`PROGRESS_LINES`](Self::PROGRESS_LINES) lines.
+    ///
+    /// # Errors
+    /// Return `GraphParseError` in case of some I/O or parsing problems.
+    ///
+    /// ```
+    /// use trivial_graph::Graph;
+    /// let graph = Graph::path(1500, |v| v);
+    /// let graph_string = graph.to_string();
+    /// let mut reported = Vec::new();
+    /// let parsed = Graph::<usize>::from_reader_with_progress(&mut graph_string.as_bytes(), |lines| {
+    ///     reported.push(lines)
+    /// })
+    /// .unwrap();
+    /// assert_eq!(parsed, graph);
+    /// assert_eq!(reported, vec![1000, 2000, 3000]);
+    ///
+    /// // 999 lines, end of data is not counted as a line.
+    /// let graph_string = format!("1 1\n#\n{}", "1 1\n".repeat(997));
+    /// let mut reported = Vec::new();
+    /// let parsed = Graph::<usize>::from_reader_with_progress(&mut graph_string.as_bytes(), |lines| {
+    ///     reported.push(lines)
+    /// });
+    /// assert!(parsed.is_ok());
+    /// assert!(reported.is_empty());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_reader_with_progress<R: Read, P: FnMut(usize)>(
+        reader: &mut R,
+        mut progress: P,
+    ) -> Result<Self, GraphParseError<T::Err>> {
+        Self::read_graph(
+            &mut BufReader::new(reader),
+            &ParseOptions::default(),
+            &mut 0,
+            &mut ParseReport::default(),
+            &mut progress,
+        )
+    }
+
+    /// Reads all graphs from given reader and return them in order.
+    ///
+    /// Each graph is written in format of [`Graph::from_reader`], graphs are separated by an empty line
+    /// which ends edges section of the previous graph.
+    ///
+    /// # Errors
+    /// Return `GraphParseError` in case of some I/O or parsing problems in any of graphs.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graphs_string = concat!(
+    /// "1 1\n",
+    /// "2 2\n",
+    /// "#\n",
+    /// "1 2\n",
+    /// "\n",
+    /// "3 3\n",
+    /// "#\n",
+    /// "3 3\n"
+    /// ).as_bytes();
+    /// let graphs = Graph::<i32>::many_from_reader(&mut graphs_string).unwrap();
+    /// assert_eq!(graphs.len(), 2);
+    /// assert_eq!(graphs[0].get_vertices_ids(), HashSet::from([1, 2]));
+    /// assert_eq!(graphs[0].get_neighbours(1), Some(HashSet::from([2])));
+    /// assert_eq!(graphs[1].get_vertices_ids(), HashSet::from([3]));
+    /// assert_eq!(graphs[1].get_neighbours(3), Some(HashSet::from([3])));
+    /// ```
+    ///
+    /// Extra blank lines between graphs and at the end of data are skipped.
+    /// ```
+    /// use std::num::ParseIntError;
+    /// use trivial_graph::{Graph, GraphParseError};
+    /// let graphs_string = "\n1 1\n#\n1 1\n\n \n\n2 2\n#\n\n\n";
+    /// let graphs = Graph::<i32>::many_from_reader(&mut graphs_string.as_bytes()).unwrap();
+    /// assert_eq!(graphs.len(), 2);
+    /// assert_eq!(graphs[1].get_vertex(2).unwrap().value, 2);
+    /// assert!(Graph::<i32>::many_from_reader(&mut "\n\n".as_bytes()).unwrap().is_empty());
+    ///
+    /// let err = Graph::<i32>::many_from_reader(&mut "1 1\n#\n\n\n2 x\n".as_bytes()).unwrap_err();
+    /// if let GraphParseError::<ParseIntError>::ValueParseError(err) = err {
+    ///     assert_eq!(err.line(), 5);
+    /// } else {
+    ///     assert!(false, "Incorrect error type");
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn many_from_reader<R: Read>(reader: &mut R) -> Result<Vec<Self>, GraphParseError<T::Err>> {
+        let options = ParseOptions::default();
         let mut buf_reader = BufReader::new(reader);
+        let mut line_number = 0;
+        let mut graphs = Vec::new();
+        while Self::skip_blank_lines(&mut buf_reader, &mut line_number)? {
+            graphs.push(Self::read_graph(
+                &mut buf_reader,
+                &options,
+                &mut line_number,
+                &mut ParseReport::default(),
+                &mut |_| {},
+            )?);
+        }
+        Ok(graphs)
+    }
+
+    /// Consume lines of `reader` containing only whitespace, advancing `line_number`.
+    /// Returns `false` if end of data is reached.
+    #[cfg(feature = "std")]
+    fn skip_blank_lines<R: BufRead>(
+        reader: &mut R,
+        line_number: &mut usize,
+    ) -> std::io::Result<bool> {
+        loop {
+            let buf = reader.fill_buf()?;
+            if buf.is_empty() {
+                return Ok(false);
+            }
+            let data_start = buf.iter().position(|b| !b.is_ascii_whitespace());
+            // Keep the line with data, consume only lines before it.
+            let consumed = match data_start {
+                Some(pos) => buf[..pos]
+                    .iter()
+                    .rposition(|&b| b == b'\n')
+                    .map_or(0, |p| p + 1),
+                None => buf.len(),
+            };
+            *line_number += buf[..consumed].iter().filter(|&&b| b == b'\n').count();
+            reader.consume(consumed);
+            if data_start.is_some() {
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Reads graph in adjacency list format: each line holds id of vertex, colon and ids of its
+    /// out-neighbours separated by whitespace, like `1: 2 3`. Vertex without neighbours is written as `1:`.
+    ///
+    /// All mentioned vertices are created with value `T::default()`. Lines repeating vertex add more
+    /// neighbours to it. Empty lines are skipped.
+    ///
+    /// # Errors
+    /// Returns [`GraphParseError::MissingColon`] if line has no colon
+    /// and [`GraphParseError::VertexParseError`] if some id is not a number.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::Graph;
+    /// let mut graph_string = concat!(
+    /// "1: 2 3\n",
+    /// "\n",
+    /// "2:\n",
+    /// "3:3\n",
+    /// "4 :  1\n",
+    /// ).as_bytes();
+    /// let graph = Graph::<String>::read_adjacency_list(&mut graph_string).unwrap();
+    /// assert_eq!(graph.get_vertices_ids(), HashSet::from([1, 2, 3, 4]));
+    /// assert_eq!(graph.get_vertex(1).unwrap().value, "");
+    /// assert_eq!(graph.get_neighbours(1), Some(HashSet::from([2, 3])));
+    /// assert_eq!(graph.get_neighbours(2), Some(HashSet::new()));
+    /// assert_eq!(graph.get_neighbours(3), Some(HashSet::from([3])));
+    /// assert_eq!(graph.get_neighbours(4), Some(HashSet::from([1])));
+    /// ```
+    ///
+    /// ```
+    /// use std::num::ParseIntError;
+    /// use trivial_graph::{Graph, GraphParseError};
+    /// let err = Graph::<i32>::read_adjacency_list(&mut "1: 2\n2 3\n".as_bytes()).unwrap_err();
+    /// assert!(matches!(err, GraphParseError::<ParseIntError>::MissingColon(2)));
+    /// let err = Graph::<i32>::read_adjacency_list(&mut "1: 2\n2: x\n".as_bytes()).unwrap_err();
+    /// if let GraphParseError::<ParseIntError>::VertexParseError(err) = err {
+    ///     assert_eq!(err.token(), "x");
+    ///     assert_eq!(err.line(), 2);
+    /// } else {
+    ///     assert!(false, "Incorrect error type");
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn read_adjacency_list<R: Read>(reader: &mut R) -> Result<Self, GraphParseError<T::Err>>
+    where
+        T: Default,
+    {
+        let mut graph = Self::new();
+        for (line_number, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let Some((vertex, neighbours)) = line.split_once(':') else {
+                return Err(GraphParseError::MissingColon(line_number + 1));
+            };
+            let vertex = Self::parse_vertex_id(vertex.trim(), line_number + 1)?;
+            let neighbours = neighbours
+                .split_whitespace()
+                .map(|token| Self::parse_vertex_id(token, line_number + 1))
+                .collect::<Result<Vec<_>, _>>()?;
+            if !graph.vertices.contains_key(&vertex) {
+                graph.add_vertex(vertex, T::default());
+            }
+            for nx in neighbours {
+                graph.add_edge_auto(vertex, nx);
+            }
+        }
+        Ok(graph)
+    }
+
+    #[cfg(feature = "std")]
+    fn parse_vertex_id(token: &str, line: usize) -> Result<usize, VertexIdParseError> {
+        token
+            .parse()
+            .map_err(|e| VertexIdParseError::new(token, line, e))
+    }
+
+    /// Reads single graph from `reader`, stopping after empty line or end of data in edges section.
+    /// Empty lines in vertices section are skipped.
+    ///
+    /// `line_number` holds number of lines consumed before and is advanced by lines read,
+    /// statistics of read lines are added to `report`.
+    #[cfg(feature = "std")]
+    fn read_graph<R: BufRead>(
+        reader: &mut R,
+        options: &ParseOptions,
+        line_number: &mut usize,
+        report: &mut ParseReport,
+        progress: &mut dyn FnMut(usize),
+    ) -> Result<Self, GraphParseError<T::Err>> {
+        let mut graph = Self::new();
         let mut buf = String::new();
         loop {
             buf.clear();
-            buf_reader.read_line(&mut buf)?;
-            let line = buf.trim();
-            if line == "#" || line.is_empty() {
+            if reader.read_line(&mut buf)? == 0 {
+                break;
+            }
+            *line_number += 1;
+            if *line_number % Self::PROGRESS_LINES == 0 {
+                progress(*line_number);
+            }
+            let mut line = buf.trim();
+            if line == options.separator {
                 break;
             }
-            let parts: Vec<_> = line.splitn(2, ' ').collect();
+            if options.inline_comments {
+                line = strip_comment(line, options);
+            }
+            if line.is_empty() {
+                continue;
+            }
+            let parts: Vec<_> = line.splitn(2, options.delimiter).collect();
             if parts.len() < 2 {
                 return Err(GraphParseError::DataError(2, parts.len()));
             }
 
-            let vertex_id: usize = parts[0].parse()?;
-            let value: T = parts[1]
+            let vertex_id = Self::parse_vertex_id(parts[0], *line_number)?;
+            let value = if options.trim_values {
+                parts[1].trim()
+            } else {
+                parts[1]
+            };
+            let unquoted;
+            let value = if options.quoted_values && value.starts_with('"') {
+                unquoted = unquote(value).ok_or(GraphParseError::QuotedValueError(*line_number))?;
+                unquoted.as_str()
+            } else {
+                value
+            };
+            let value: T = value
                 .parse()
-                .map_err(VertexValueParseError::from)?;
+                .map_err(|e| VertexValueParseError::new(value, *line_number, e))?;
+            if graph.vertices.contains_key(&vertex_id) {
+                if options.reject_duplicate_vertices {
+                    return Err(GraphParseError::DuplicateVertex(vertex_id, *line_number));
+                }
+                report.duplicate_vertices += 1;
+            }
             graph.add_vertex(vertex_id, value);
         }
         loop {
             buf.clear();
-            buf_reader.read_line(&mut buf)?;
-            let line = buf.trim();
+            if reader.read_line(&mut buf)? == 0 {
+                break;
+            }
+            *line_number += 1;
+            if *line_number % Self::PROGRESS_LINES == 0 {
+                progress(*line_number);
+            }
+            let mut line = buf.trim();
             if line.is_empty() {
                 break;
             }
-            let parts: Vec<_> = line.splitn(3, ' ').collect();
+            if line == options.separator {
+                return Err(GraphParseError::RepeatedSeparator(*line_number));
+            }
+            if options.inline_comments {
+                line = strip_comment(line, options);
+                if line.is_empty() {
+                    continue;
+                }
+            }
+            let parts: Vec<_> = line.splitn(3, options.delimiter).collect();
             if parts.len() < 2 {
                 return Err(GraphParseError::DataError(2, parts.len()));
             }
-            let vertex_from_id: usize = parts[0].parse()?;
-            let vertex_to_id: usize = parts[1].parse()?;
-            graph.add_edge(vertex_from_id, vertex_to_id)?;
+            let vertex_from_id = Self::parse_vertex_id(parts[0], *line_number)?;
+            let vertex_to_id = Self::parse_vertex_id(parts[1], *line_number)?;
+            if graph.has_edge(vertex_from_id, vertex_to_id) {
+                report.duplicate_edges += 1;
+            }
+            graph.add_edge(vertex_from_id, vertex_to_id).map_err(|e| {
+                GraphParseError::EdgeVertexNotExists(e, *line_number, line.to_string())
+            })?;
         }
         Ok(graph)
     }
 }
 
+/// Writes graph in format accepted by [`Graph::from_reader`]: vertex lines, `#` line and edge lines.
+///
+/// Separator line is written even if graph has no vertices or no edges, so such graphs are read back unchanged.
+///
+/// ```
+/// use trivial_graph::Graph;
+/// let graph = Graph::<i32>::new();
+/// assert_eq!(graph.to_string(), "#\n");
+///
+/// let mut graph = Graph::new();
+/// graph.add_vertex(1, 10);
+/// graph.add_vertex(2, 20);
+/// assert!(graph.add_edge(2, 1).is_ok());
+/// let graph_string = graph.to_string();
+/// assert!(graph_string.contains("1 10\n") && graph_string.contains("2 20\n"));
+/// assert!(graph_string.ends_with("#\n2 1\n"));
+/// ```
 impl<T: FromStr + Display> Display for Graph<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         for v in self.vertices.values() {
             writeln!(f, "{} {}", v.id, v.value)?;
         }
@@ -368,3 +2552,140 @@ impl<T: FromStr + Display> Display for Graph<T> {
         Ok(())
     }
 }
+
+/// Parses graph in the same format as [`Graph::from_reader`], see [`Graph::from_str_graph`].
+///
+/// ```
+/// use trivial_graph::collections::HashSet;
+/// use trivial_graph::Graph;
+/// let mut graph = Graph::new();
+/// graph.add_vertex(1, 10);
+/// graph.add_vertex(2, 20);
+/// assert!(graph.add_edge(2, 1).is_ok());
+/// let parsed: Graph<i32> = graph.to_string().parse().unwrap();
+/// assert_eq!(parsed.get_vertex(2).unwrap().value, 20);
+/// assert_eq!(parsed.get_neighbours(2), Some(HashSet::from([1])));
+/// assert_eq!(parsed.get_neighbours(1), Some(HashSet::new()));
+/// let empty: Graph<i32> = Graph::<i32>::new().to_string().parse().unwrap();
+/// assert!(empty.get_vertices_ids().is_empty());
+/// assert!("1 1\n#\n1 2\n".parse::<Graph<i32>>().is_err());
+/// ```
+#[cfg(feature = "std")]
+impl<T: FromStr + Display> FromStr for Graph<T> {
+    type Err = GraphParseError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_graph(s)
+    }
+}
+
+/// Consumes graph, yielding its vertices in arbitrary order.
+///
+/// Edges are dropped, use [`Graph::into_parts`] to keep them.
+///
+/// ```
+/// use trivial_graph::Graph;
+/// let mut graph = Graph::new();
+/// graph.add_vertex(1, "node".to_string());
+/// graph.add_vertex(2, "node2".to_string());
+/// assert!(graph.add_edge(1, 2).is_ok());
+/// let mut values: Vec<String> = graph.into_iter().map(|v| v.value).collect();
+/// values.sort();
+/// assert_eq!(values, vec!["node", "node2"]);
+/// ```
+impl<T: FromStr + Display> IntoIterator for Graph<T> {
+    type Item = GraphVertex<T>;
+    type IntoIter = hash_map::IntoValues<usize, GraphVertex<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vertices.into_values()
+    }
+}
+
+/// Iterates over vertices of graph in arbitrary order.
+///
+/// ```
+/// use trivial_graph::Graph;
+/// let mut graph = Graph::new();
+/// graph.add_vertex(1, 10);
+/// graph.add_vertex(2, 20);
+/// let mut sum = 0;
+/// for v in &graph {
+///     sum += v.value;
+/// }
+/// assert_eq!(sum, 30);
+/// ```
+impl<'a, T: FromStr + Display> IntoIterator for &'a Graph<T> {
+    type Item = &'a GraphVertex<T>;
+    type IntoIter = hash_map::Values<'a, usize, GraphVertex<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vertices.values()
+    }
+}
+
+/// Graphs are equal if they have the same vertices with equal values and the same edges.
+///
+/// Vertex attributes, edge labels and presence of predecessor index are not compared.
+///
+/// ```
+/// use trivial_graph::Graph;
+/// let mut graph = Graph::new();
+/// let mut other = Graph::with_predecessor_index();
+/// for i in 1..=3 {
+///     graph.add_vertex(i, i.to_string());
+///     other.add_vertex(4 - i, (4 - i).to_string());
+/// }
+/// assert!(graph.add_edge(1, 2).is_ok());
+/// assert!(other.add_edge(1, 2).is_ok());
+/// assert_eq!(graph, other);
+/// assert!(other.add_edge(2, 1).is_ok());
+/// assert_ne!(graph, other);
+/// ```
+impl<T: FromStr + Display + PartialEq> PartialEq for Graph<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.vertices.len() == other.vertices.len()
+            && self
+                .vertices
+                .iter()
+                .all(|(id, v)| other.vertices.get(id).is_some_and(|u| u.value == v.value))
+            && self.edges == other.edges
+    }
+}
+
+impl<T: FromStr + Display + Eq> Eq for Graph<T> {}
+
+/// Hash is independent of order of vertices and edges in storage and consistent with [`PartialEq`],
+/// so graph may be used as key of [`HashMap`].
+///
+/// Note: sorts all vertices and edges before hashing them, so takes `O(V log V + E log E)` time.
+///
+/// ```
+/// use trivial_graph::collections::HashMap;
+/// use trivial_graph::Graph;
+/// let mut graph = Graph::new();
+/// let mut other = Graph::new();
+/// for i in 1..=3 {
+///     graph.add_vertex(i, i.to_string());
+///     other.add_vertex(4 - i, (4 - i).to_string());
+/// }
+/// assert!(graph.add_edge(1, 2).is_ok());
+/// assert!(graph.add_edge(3, 2).is_ok());
+/// assert!(other.add_edge(3, 2).is_ok());
+/// assert!(other.add_edge(1, 2).is_ok());
+/// let mut cache = HashMap::new();
+/// cache.insert(graph, 42);
+/// assert_eq!(cache.get(&other), Some(&42));
+/// ```
+impl<T: FromStr + Display + Hash> Hash for Graph<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut vertices: Vec<_> = self.vertices.values().collect();
+        vertices.sort_unstable_by_key(|v| v.id);
+        vertices.len().hash(state);
+        for v in vertices {
+            v.id.hash(state);
+            v.value.hash(state);
+        }
+        self.edges_sorted().hash(state);
+    }
+}
@@ -1,16 +1,85 @@
-use std::fmt::Display;
-use std::str::FromStr;
+use core::fmt::Display;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+use crate::collections::HashMap;
+use crate::prelude::*;
 
 /// Vertex of a graph.
-#[derive(Debug)]
+///
+/// Besides value, vertex may hold string attributes (e.g. color or group) used as visualization hints,
+/// they are written as node attributes by [`Graph::to_dot`](crate::Graph::to_dot).
+///
+/// Vertices are equal if both ids and values are equal, attributes are not compared.
+/// Hash is computed from id only, as id identifies vertex in graph.
+///
+/// ```
+/// use trivial_graph::collections::HashSet;
+/// use trivial_graph::GraphVertex;
+/// assert_eq!(GraphVertex::new(1, 10), GraphVertex::new(1, 10));
+/// assert_ne!(GraphVertex::new(1, 10), GraphVertex::new(1, 20));
+/// assert_ne!(GraphVertex::new(1, 10), GraphVertex::new(2, 10));
+/// let vertices: HashSet<_> = HashSet::from([GraphVertex::new(1, 10), GraphVertex::new(1, 20)]);
+/// assert_eq!(vertices.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
 pub struct GraphVertex<T: FromStr + Display> {
     pub id: usize,
     pub value: T,
+    attributes: HashMap<String, String>,
 }
 
 impl<T: FromStr + Display> GraphVertex<T> {
     /// Create new vertex with given id and value
     pub fn new(id: usize, value: T) -> Self {
-        Self { id, value }
+        Self {
+            id,
+            value,
+            attributes: HashMap::new(),
+        }
+    }
+
+    /// Set attribute of vertex, returns previous value of attribute if it was set.
+    ///
+    /// ```
+    /// use trivial_graph::GraphVertex;
+    /// let mut vertex = GraphVertex::new(1, 10);
+    /// assert_eq!(vertex.set_attr("color", "red"), None);
+    /// assert_eq!(vertex.set_attr("color", "blue"), Some("red".to_string()));
+    /// assert_eq!(vertex.get_attr("color"), Some("blue"));
+    /// assert_eq!(vertex.get_attr("group"), None);
+    /// assert_eq!(vertex, GraphVertex::new(1, 10));
+    /// ```
+    pub fn set_attr(&mut self, key: impl Into<String>, value: impl Into<String>) -> Option<String> {
+        self.attributes.insert(key.into(), value.into())
+    }
+
+    /// Get attribute of vertex, `None` if it is not set.
+    pub fn get_attr(&self, key: &str) -> Option<&str> {
+        self.attributes.get(key).map(String::as_str)
+    }
+
+    /// Remove attribute of vertex, returns its value if it was set.
+    pub fn remove_attr(&mut self, key: &str) -> Option<String> {
+        self.attributes.remove(key)
+    }
+
+    /// Get all attributes of vertex.
+    pub fn attrs(&self) -> &HashMap<String, String> {
+        &self.attributes
+    }
+}
+
+impl<T: FromStr + Display + PartialEq> PartialEq for GraphVertex<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.value == other.value
+    }
+}
+
+impl<T: FromStr + Display + Eq> Eq for GraphVertex<T> {}
+
+impl<T: FromStr + Display> Hash for GraphVertex<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
     }
 }
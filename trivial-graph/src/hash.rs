@@ -0,0 +1,111 @@
+//! Maps and sets keyed by vertex ids used inside [`Graph`](crate::Graph).
+//!
+//! Both are internal: public methods accept and return [`crate::collections`] types or iterators,
+//! so features below change only storage, not types of public API.
+//!
+//! With `fxhash` feature they use fast non-cryptographic hasher instead of default `SipHash`.
+//!
+//! With `ordered-neighbours` feature [`IdSet`] is an `IndexSet` remembering insertion order,
+//! so neighbours are iterated in order edges were added, and traversals visit siblings in that order.
+//! It costs extra `Vec` of entries per set and `O(n)` removal to preserve order of remaining elements.
+//! Neighbours set at once by [`Graph::set_neighbours`](crate::Graph::set_neighbours) come in arbitrary order.
+//!
+//! ```
+//! use trivial_graph::{DfsVisitor, Graph, GraphVisitor};
+//! let mut graph = Graph::new();
+//! for i in 1..=5 {
+//!     graph.add_vertex(i, i.to_string());
+//! }
+//! for i in [4, 2, 5, 3] {
+//!     assert!(graph.add_edge(1, i).is_ok());
+//! }
+//! if cfg!(feature = "ordered-neighbours") {
+//!     assert_eq!(DfsVisitor::new(&graph).collect_from(1), vec![1, 4, 2, 5, 3]);
+//! }
+//! ```
+
+use crate::collections::{HashMap, HashSet};
+
+#[cfg(all(not(feature = "fxhash"), feature = "std"))]
+pub(crate) type IdHasher = std::collections::hash_map::RandomState;
+#[cfg(all(not(feature = "fxhash"), not(feature = "std")))]
+pub(crate) type IdHasher = hashbrown::DefaultHashBuilder;
+#[cfg(feature = "fxhash")]
+pub(crate) type IdHasher = core::hash::BuildHasherDefault<rustc_hash::FxHasher>;
+
+/// Map from vertex ids used for graph storage.
+///
+/// Not exposed in public API, which uses [`crate::collections`], so enabling `fxhash` doesn't change public types.
+pub(crate) type IdMap<V> = HashMap<usize, V, IdHasher>;
+/// Set of vertex ids used for graph storage.
+#[cfg(not(feature = "ordered-neighbours"))]
+pub(crate) type IdSet = HashSet<usize, IdHasher>;
+/// Set of vertex ids used for graph storage.
+#[cfg(feature = "ordered-neighbours")]
+pub(crate) type IdSet = indexmap::IndexSet<usize, IdHasher>;
+
+/// Convert stored set into set with default hasher returned by public methods.
+#[cfg(not(any(feature = "fxhash", feature = "ordered-neighbours")))]
+pub(crate) fn to_public_set(set: &IdSet) -> HashSet<usize> {
+    set.clone()
+}
+
+#[cfg(any(feature = "fxhash", feature = "ordered-neighbours"))]
+pub(crate) fn to_public_set(set: &IdSet) -> HashSet<usize> {
+    set.iter().copied().collect()
+}
+
+/// Convert stored set into set with default hasher without copying when types match.
+#[cfg(not(any(feature = "fxhash", feature = "ordered-neighbours")))]
+pub(crate) fn into_public_set(set: IdSet) -> HashSet<usize> {
+    set
+}
+
+#[cfg(any(feature = "fxhash", feature = "ordered-neighbours"))]
+pub(crate) fn into_public_set(set: IdSet) -> HashSet<usize> {
+    set.into_iter().collect()
+}
+
+/// Convert set with default hasher accepted by public methods into stored set.
+#[cfg(not(any(feature = "fxhash", feature = "ordered-neighbours")))]
+pub(crate) fn from_public_set(set: HashSet<usize>) -> IdSet {
+    set
+}
+
+#[cfg(any(feature = "fxhash", feature = "ordered-neighbours"))]
+pub(crate) fn from_public_set(set: HashSet<usize>) -> IdSet {
+    set.into_iter().collect()
+}
+
+/// Convert stored map into map with default hasher returned by public methods.
+#[cfg(not(feature = "fxhash"))]
+pub(crate) fn into_public_map<V>(map: IdMap<V>) -> HashMap<usize, V> {
+    map
+}
+
+#[cfg(feature = "fxhash")]
+pub(crate) fn into_public_map<V>(map: IdMap<V>) -> HashMap<usize, V> {
+    map.into_iter().collect()
+}
+
+/// Convert map with default hasher accepted by public methods into stored map.
+#[cfg(not(feature = "fxhash"))]
+pub(crate) fn from_public_map<V>(map: HashMap<usize, V>) -> IdMap<V> {
+    map
+}
+
+#[cfg(feature = "fxhash")]
+pub(crate) fn from_public_map<V>(map: HashMap<usize, V>) -> IdMap<V> {
+    map.into_iter().collect()
+}
+
+/// Remove `id` from stored set, keeping order of remaining ids.
+#[cfg(not(feature = "ordered-neighbours"))]
+pub(crate) fn remove_id(set: &mut IdSet, id: usize) {
+    set.remove(&id);
+}
+
+#[cfg(feature = "ordered-neighbours")]
+pub(crate) fn remove_id(set: &mut IdSet, id: usize) {
+    set.shift_remove(&id);
+}
@@ -1,8 +1,36 @@
-pub use graph::{Graph, GraphParseError};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "hashbrown")))]
+compile_error!("either `std` or `hashbrown` feature must be enabled");
+
+pub use algorithms::{EdgeKind, GraphDiff};
+#[cfg(feature = "std")]
+pub use binary::BinaryEncode;
+pub use graph::{
+    CycleError, EdgeNotExistsError, Graph, GraphInvariantError, RenameError, VertexExistsError,
+    VertexNotExistsError,
+};
+#[cfg(feature = "std")]
+pub use graph::{
+    DynGraphParseError, ErasedValueError, GraphParseError, VertexIdParseError,
+    VertexValueParseError,
+};
 pub use graph_vertex::GraphVertex;
+#[cfg(feature = "std")]
+pub use parse_options::{ParseOptions, ParseReport};
 pub use visitors::*;
 
+mod algorithms;
+#[cfg(feature = "std")]
+mod binary;
+pub mod collections;
+mod generators;
 mod graph;
 mod graph_vertex;
+mod hash;
+mod parse_options;
+mod prelude;
 mod visitors;
 
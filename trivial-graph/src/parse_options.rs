@@ -0,0 +1,111 @@
+use crate::prelude::*;
+
+/// Settings of graph parsing, see [`Graph::from_reader_with_options`](crate::Graph::from_reader_with_options).
+///
+/// Default options correspond to behaviour of [`Graph::from_reader`](crate::Graph::from_reader).
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct ParseOptions {
+    /// Line separating vertices section from edges section, `#` by default.
+    ///
+    /// Empty lines in vertices section are skipped.
+    pub separator: String,
+    /// Character separating fields of vertex and edge lines, space by default.
+    pub delimiter: char,
+    /// Trim whitespace around value of vertex before parsing it.
+    ///
+    /// By default value is everything after the first delimiter of the line with line end whitespace removed,
+    /// so whitespace after the delimiter is kept.
+    pub trim_values: bool,
+    /// Fail with [`GraphParseError::DuplicateVertex`](crate::GraphParseError::DuplicateVertex)
+    /// if some vertex is defined more than once instead of keeping the last value.
+    pub reject_duplicate_vertices: bool,
+    /// Read values starting with double quote as quoted strings: value ends at the closing quote,
+    /// `\"` and `\\` inside are unescaped, so value may contain delimiters. Other values are read as usual.
+    ///
+    /// [`Graph::write_with_options`](crate::Graph::write_with_options) quotes values which need it
+    /// when this option is set, so such graphs are read back unchanged.
+    pub quoted_values: bool,
+    /// Strip comments from data lines: comment starts with `#` at the beginning of line or after
+    /// whitespace or delimiter and lasts to the end of line. Lines with only comment are skipped.
+    ///
+    /// Line consisting only of separator is never treated as comment.
+    /// With [`ParseOptions::quoted_values`] `#` inside quoted value doesn't start comment.
+    pub inline_comments: bool,
+}
+
+#[cfg(feature = "std")]
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            separator: "#".to_string(),
+            delimiter: ' ',
+            trim_values: false,
+            reject_duplicate_vertices: false,
+            quoted_values: false,
+            inline_comments: false,
+        }
+    }
+}
+
+/// Unescape value enclosed in double quotes, `None` if it is not properly quoted.
+#[cfg(feature = "std")]
+pub(crate) fn unquote(value: &str) -> Option<String> {
+    let mut chars = value.strip_prefix('"')?.chars();
+    let mut result = String::new();
+    loop {
+        match chars.next()? {
+            '"' => break,
+            '\\' => match chars.next()? {
+                c @ ('"' | '\\') => result.push(c),
+                c => {
+                    result.push('\\');
+                    result.push(c);
+                }
+            },
+            c => result.push(c),
+        }
+    }
+    chars.as_str().is_empty().then_some(result)
+}
+
+/// Enclose value in double quotes, escaping quotes and backslashes.
+pub(crate) fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Remove comment started by `#` from line, see [`ParseOptions::inline_comments`].
+#[cfg(feature = "std")]
+pub(crate) fn strip_comment<'a>(line: &'a str, options: &ParseOptions) -> &'a str {
+    let mut in_quotes = false;
+    let mut escaped = false;
+    let mut previous = None;
+    for (i, c) in line.char_indices() {
+        if in_quotes {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_quotes = false,
+                _ => {}
+            }
+        } else if c == '"' && options.quoted_values {
+            in_quotes = true;
+        } else if c == '#'
+            && previous.is_none_or(|p: char| p.is_whitespace() || p == options.delimiter)
+        {
+            return line[..i].trim_end();
+        }
+        previous = Some(c);
+    }
+    line
+}
+
+/// Statistics collected while parsing graph, see [`Graph::from_reader_with_report`](crate::Graph::from_reader_with_report).
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseReport {
+    /// Number of vertex lines repeating id of previously defined vertex.
+    pub duplicate_vertices: usize,
+    /// Number of edge lines repeating previously defined edge.
+    pub duplicate_edges: usize,
+}
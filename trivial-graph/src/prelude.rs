@@ -0,0 +1,6 @@
+//! Items of std prelude missing from core prelude, so modules build the same way without `std`.
+
+pub(crate) use alloc::format;
+pub(crate) use alloc::string::{String, ToString};
+pub(crate) use alloc::vec;
+pub(crate) use alloc::vec::Vec;
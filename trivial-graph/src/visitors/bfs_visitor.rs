@@ -1,13 +1,15 @@
-use std::collections::{HashSet, VecDeque};
-use std::fmt::Display;
-use std::str::FromStr;
+use alloc::collections::VecDeque;
+use core::fmt::Display;
+use core::str::FromStr;
 
+use crate::collections::HashSet;
+use crate::prelude::*;
 use crate::{Graph, GraphVertex, GraphVisitor};
 
 /// Helper for storing state between bfs runs in graph.
 ///
 /// ```
-/// use std::collections::HashSet;
+/// use trivial_graph::collections::HashSet;
 /// use trivial_graph::{BfsVisitor, Graph, GraphVertex, GraphVisitor, VisitOrder};
 /// let mut graph = Graph::new();
 /// graph.add_vertex(1, "node".to_string());
@@ -51,6 +53,8 @@ use crate::{Graph, GraphVertex, GraphVisitor};
 /// ```
 pub struct BfsVisitor<'a, T: FromStr + Display> {
     visited: HashSet<usize>,
+    /// Vertices traversed since the last [`BfsVisitor::revisit_frontier`], in order of traversal.
+    frontier: Vec<usize>,
     graph: &'a Graph<T>,
 }
 
@@ -59,25 +63,248 @@ impl<'a, T: FromStr + Display> BfsVisitor<'a, T> {
     pub fn new(graph: &'a Graph<T>) -> Self {
         Self {
             visited: Default::default(),
+            frontier: Vec::new(),
             graph,
         }
     }
 
-    fn bfs_impl<F: FnMut(&GraphVertex<T>)>(&mut self, v: usize, f: &mut F) {
+    /// Creates new visitor for given graph with vertices from `initial` already marked as visited.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::{BfsVisitor, Graph, GraphVisitor};
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// graph.add_vertex(3, "node3".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// let mut visitor = BfsVisitor::with_visited(&graph, HashSet::from([2]));
+    /// assert_eq!(visitor.collect_from(1), vec![1]);
+    /// ```
+    pub fn with_visited(graph: &'a Graph<T>, initial: HashSet<usize>) -> Self {
+        Self {
+            visited: initial,
+            frontier: Vec::new(),
+            graph,
+        }
+    }
+
+    /// Mark vertex as visited, so following runs will not visit it.
+    ///
+    /// ```
+    /// use trivial_graph::{BfsVisitor, Graph, GraphVisitor};
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// graph.add_vertex(3, "node3".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// let mut visitor = BfsVisitor::new(&graph);
+    /// visitor.mark_visited(3);
+    /// assert_eq!(visitor.collect_from(1), vec![1, 2]);
+    /// ```
+    pub fn mark_visited(&mut self, vertex: usize) {
+        self.visited.insert(vertex);
+    }
+
+    /// Check if vertex was visited by previous runs or marked as visited.
+    ///
+    /// ```
+    /// use trivial_graph::{BfsVisitor, Graph, GraphVisitor};
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// graph.add_vertex(3, "node3".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// let mut visitor = BfsVisitor::new(&graph);
+    /// visitor.visit(1, |_| {});
+    /// assert!(visitor.is_visited(2));
+    /// assert!(!visitor.is_visited(3));
+    /// assert_eq!(visitor.visited_count(), 2);
+    /// ```
+    pub fn is_visited(&self, vertex: usize) -> bool {
+        self.visited.contains(&vertex)
+    }
+
+    /// Get number of visited vertices.
+    pub fn visited_count(&self) -> usize {
+        self.visited.len()
+    }
+
+    /// Visit vertices with `bfs` algorithm starting from `start`, moving only into vertices satisfying `allow`.
+    ///
+    /// Vertices not satisfying `allow` are neither visited nor marked as visited, so later runs may visit them.
+    ///
+    /// ```
+    /// use trivial_graph::{BfsVisitor, Graph, GraphVisitor};
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "red".to_string());
+    /// graph.add_vertex(2, "red".to_string());
+    /// graph.add_vertex(3, "blue".to_string());
+    /// graph.add_vertex(4, "red".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(3, 4).is_ok());
+    /// let mut visited_vertices = Vec::new();
+    /// let mut visitor = BfsVisitor::new(&graph);
+    /// visitor.visit_filtered(1, |v| v.value == "red", |v| visited_vertices.push(v.id));
+    /// assert_eq!(visited_vertices, vec![1, 2]);
+    /// assert!(!visitor.is_visited(3));
+    /// ```
+    pub fn visit_filtered<P: Fn(&GraphVertex<T>) -> bool, F: FnMut(&GraphVertex<T>)>(
+        &mut self,
+        start: usize,
+        allow: P,
+        mut f: F,
+    ) {
+        self.bfs_impl(start, &allow, &mut f);
+    }
+
+    /// Visit vertices with `bfs` algorithm starting from `start`, passing to `f` also id of vertex
+    /// from which the visited one was discovered, `None` for `start`.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashMap;
+    /// use trivial_graph::{BfsVisitor, Graph, GraphVisitor};
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(3, 4).is_ok());
+    /// assert!(graph.add_edge(4, 1).is_ok());
+    /// let mut parents = HashMap::new();
+    /// let mut visitor = BfsVisitor::new(&graph);
+    /// visitor.visit_with_parent(1, |v, parent| {
+    ///     parents.insert(v.id, parent);
+    /// });
+    /// assert_eq!(
+    ///     parents,
+    ///     HashMap::from([(1, None), (2, Some(1)), (3, Some(1)), (4, Some(3))])
+    /// );
+    /// ```
+    pub fn visit_with_parent<F: FnMut(&GraphVertex<T>, Option<usize>)>(
+        &mut self,
+        start: usize,
+        mut f: F,
+    ) {
+        if self.graph.get_vertex(start).is_none() || !self.visited.insert(start) {
+            return;
+        }
+        let mut vertex_queue = VecDeque::from([(start, None)]);
+        while let Some((v, parent)) = vertex_queue.pop_front() {
+            self.frontier.push(v);
+            f(self.graph.get_vertex(v).unwrap(), parent);
+            for nx in self.graph.neighbours_iter(v) {
+                if self.visited.insert(nx) {
+                    vertex_queue.push_back((nx, Some(v)));
+                }
+            }
+        }
+    }
+
+    /// Consume visitor returning set of visited vertices.
+    ///
+    /// As visitor borrows graph, graph can't be changed while visitor is alive, so to continue traversal
+    /// after modification, create new visitor with [`BfsVisitor::with_visited`] from returned set.
+    /// Use [`BfsVisitor::into_parts`] instead to keep frontier for [`BfsVisitor::revisit_frontier`].
+    pub fn into_visited(self) -> HashSet<usize> {
+        self.visited
+    }
+
+    /// Consume visitor returning set of visited vertices and frontier: vertices traversed
+    /// since the last [`BfsVisitor::revisit_frontier`] in order of traversal.
+    ///
+    /// Visitor holds immutable borrow of graph, so to add edges and continue traversal, take parts of visitor,
+    /// modify graph and create new visitor bound to it with [`BfsVisitor::from_parts`].
+    pub fn into_parts(self) -> (HashSet<usize>, Vec<usize>) {
+        (self.visited, self.frontier)
+    }
+
+    /// Creates new visitor for given graph from parts returned by [`BfsVisitor::into_parts`].
+    pub fn from_parts(graph: &'a Graph<T>, visited: HashSet<usize>, frontier: Vec<usize>) -> Self {
+        Self {
+            visited,
+            frontier,
+            graph,
+        }
+    }
+
+    /// Continue traversal into not visited vertices reachable by edges from the frontier, e.g. after
+    /// new edges were added to graph, see [`BfsVisitor::into_parts`].
+    ///
+    /// Frontier consists of vertices traversed by runs since the previous call, so only their edges
+    /// are examined, without rescanning all visited vertices. Vertices only marked as visited
+    /// with [`BfsVisitor::with_visited`] or [`BfsVisitor::mark_visited`] are not in frontier,
+    /// so excluded region stays excluded. Vertices traversed by this call become the new frontier.
+    ///
+    /// Frontier vertices which are absent in graph are ignored.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::{BfsVisitor, Graph, GraphVisitor};
+    /// let mut graph = Graph::new();
+    /// for i in 1..=6 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// let mut visitor = BfsVisitor::with_visited(&graph, HashSet::from([5]));
+    /// assert_eq!(visitor.collect_from(1), vec![1, 2]);
+    /// let (visited, frontier) = visitor.into_parts();
+    ///
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(3, 4).is_ok());
+    /// assert!(graph.add_edge(5, 6).is_ok());
+    /// let mut visitor = BfsVisitor::from_parts(&graph, visited, frontier);
+    /// let mut visited_vertices = Vec::new();
+    /// visitor.revisit_frontier(|v| visited_vertices.push(v.id));
+    /// assert_eq!(visited_vertices, vec![3, 4]);
+    /// assert!(!visitor.is_visited(6));
+    /// ```
+    pub fn revisit_frontier<F: FnMut(&GraphVertex<T>)>(&mut self, mut f: F) {
+        let frontier = core::mem::take(&mut self.frontier);
         let mut vertex_queue = VecDeque::new();
-        if !self.visited.contains(&v) {
+        for v in frontier {
+            for nx in self.graph.neighbours_iter(v) {
+                if self.visited.insert(nx) {
+                    vertex_queue.push_back(nx);
+                }
+            }
+        }
+        self.bfs_queue(vertex_queue, &|_| true, &mut f);
+    }
+
+    fn bfs_impl<P, F>(&mut self, v: usize, allow: &P, f: &mut F)
+    where
+        P: Fn(&GraphVertex<T>) -> bool + ?Sized,
+        F: FnMut(&GraphVertex<T>) + ?Sized,
+    {
+        let mut vertex_queue = VecDeque::new();
+        let Some(vertex) = self.graph.get_vertex(v) else {
+            return;
+        };
+        if !self.visited.contains(&v) && allow(vertex) {
             vertex_queue.push_back(v);
             self.visited.insert(v);
         }
+        self.bfs_queue(vertex_queue, allow, f);
+    }
 
+    /// Run bfs from vertices of `vertex_queue`, which must be already marked as visited.
+    fn bfs_queue<P, F>(&mut self, mut vertex_queue: VecDeque<usize>, allow: &P, f: &mut F)
+    where
+        P: Fn(&GraphVertex<T>) -> bool + ?Sized,
+        F: FnMut(&GraphVertex<T>) + ?Sized,
+    {
         while let Some(v) = vertex_queue.pop_front() {
+            self.frontier.push(v);
             f(self.graph.get_vertex(v).unwrap());
-            if let Some(neighbours) = self.graph.get_neighbours(v) {
-                for nx in neighbours {
-                    if !self.visited.contains(&nx) {
-                        self.visited.insert(nx);
-                        vertex_queue.push_back(nx);
-                    }
+            for nx in self.graph.neighbours_iter(v) {
+                if !self.visited.contains(&nx) && allow(self.graph.get_vertex(nx).unwrap()) {
+                    self.visited.insert(nx);
+                    vertex_queue.push_back(nx);
                 }
             }
         }
@@ -87,11 +314,16 @@ impl<'a, T: FromStr + Display> BfsVisitor<'a, T> {
 impl<'a, T: FromStr + Display> GraphVisitor<T> for BfsVisitor<'a, T> {
     fn visit<F: FnMut(&GraphVertex<T>)>(&mut self, vertex: usize, f: F) {
         let mut f = f;
-        self.bfs_impl(vertex, &mut f);
+        self.bfs_impl(vertex, &|_| true, &mut f);
+    }
+
+    fn visit_dyn(&mut self, vertex: usize, f: &mut dyn FnMut(&GraphVertex<T>)) {
+        self.bfs_impl(vertex, &|_| true, f);
     }
 
     fn clear(&mut self) {
         self.visited.clear();
+        self.frontier.clear();
     }
 
     fn get_graph(&self) -> &Graph<T> {
@@ -1,13 +1,14 @@
-use std::collections::HashSet;
-use std::fmt::Display;
-use std::str::FromStr;
+use core::fmt::Display;
+use core::str::FromStr;
 
+use crate::collections::HashSet;
+use crate::prelude::*;
 use crate::{Graph, GraphVertex, GraphVisitor};
 
 /// Helper for storing state between dfs runs in graph.
 ///
 /// ```
-/// use std::collections::HashSet;
+/// use trivial_graph::collections::HashSet;
 /// use trivial_graph::{DfsVisitor, Graph, GraphVertex, GraphVisitor, VisitOrder};
 /// let mut graph = Graph::new();
 /// graph.add_vertex(1, "node".to_string());
@@ -62,15 +63,129 @@ impl<'a, T: FromStr + Display> DfsVisitor<'a, T> {
         }
     }
 
-    fn dfs_impl<F: FnMut(&GraphVertex<T>)>(&mut self, v: usize, f: &mut F) {
-        if self.visited.contains(&v) {
+    /// Creates new visitor for given graph with vertices from `initial` already marked as visited.
+    ///
+    /// ```
+    /// use trivial_graph::collections::HashSet;
+    /// use trivial_graph::{DfsVisitor, Graph, GraphVisitor};
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// graph.add_vertex(3, "node3".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// let mut visitor = DfsVisitor::with_visited(&graph, HashSet::from([2]));
+    /// assert_eq!(visitor.collect_from(1), vec![1]);
+    /// ```
+    pub fn with_visited(graph: &'a Graph<T>, initial: HashSet<usize>) -> Self {
+        Self {
+            visited: initial,
+            graph,
+        }
+    }
+
+    /// Mark vertex as visited, so following runs will not visit it.
+    ///
+    /// ```
+    /// use trivial_graph::{DfsVisitor, Graph, GraphVisitor};
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// graph.add_vertex(3, "node3".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// let mut visitor = DfsVisitor::new(&graph);
+    /// visitor.mark_visited(3);
+    /// assert_eq!(visitor.collect_from(1), vec![1, 2]);
+    /// ```
+    pub fn mark_visited(&mut self, vertex: usize) {
+        self.visited.insert(vertex);
+    }
+
+    /// Check if vertex was visited by previous runs or marked as visited.
+    ///
+    /// ```
+    /// use trivial_graph::{DfsVisitor, Graph, GraphVisitor};
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// graph.add_vertex(3, "node3".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// let mut visitor = DfsVisitor::new(&graph);
+    /// visitor.visit(1, |_| {});
+    /// assert!(visitor.is_visited(2));
+    /// assert!(!visitor.is_visited(3));
+    /// assert_eq!(visitor.visited_count(), 2);
+    /// ```
+    pub fn is_visited(&self, vertex: usize) -> bool {
+        self.visited.contains(&vertex)
+    }
+
+    /// Get number of visited vertices.
+    pub fn visited_count(&self) -> usize {
+        self.visited.len()
+    }
+
+    /// Visit vertices with `dfs` algorithm starting from `start`, descending into neighbours of vertex
+    /// only if `f` returns `true` for it.
+    ///
+    /// Pruned vertex is still marked as visited, but its neighbours are not, so they may be visited
+    /// through other vertices or by later runs.
+    ///
+    /// ```
+    /// use trivial_graph::{DfsVisitor, Graph, GraphVisitor};
+    /// let mut graph = Graph::new();
+    /// for i in 1..=5 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert!(graph.add_edge(1, 4).is_ok());
+    /// assert!(graph.add_edge(4, 5).is_ok());
+    /// let mut visited_vertices = Vec::new();
+    /// let mut visitor = DfsVisitor::new(&graph);
+    /// visitor.visit_pruning(1, |v| {
+    ///     visited_vertices.push(v.id);
+    ///     v.id != 2
+    /// });
+    /// visited_vertices.sort_unstable();
+    /// assert_eq!(visited_vertices, vec![1, 2, 4, 5]);
+    /// assert!(visitor.is_visited(2));
+    /// assert!(!visitor.is_visited(3));
+    /// assert_eq!(visitor.collect_from(2), Vec::<usize>::new());
+    ///
+    /// let graph = Graph::path(100_000, |v| v);
+    /// let mut count = 0;
+    /// DfsVisitor::new(&graph).visit_pruning(0, |v| {
+    ///     count += 1;
+    ///     v.id < 90_000
+    /// });
+    /// assert_eq!(count, 90_001);
+    /// ```
+    pub fn visit_pruning<F: FnMut(&GraphVertex<T>) -> bool>(&mut self, start: usize, mut f: F) {
+        self.dfs_impl(start, &mut f);
+    }
+
+    fn dfs_impl<F: FnMut(&GraphVertex<T>) -> bool + ?Sized>(&mut self, v: usize, f: &mut F) {
+        let Some(vertex) = self.graph.get_vertex(v) else {
             return;
+        };
+        if !self.visited.insert(v) {
+            return;
+        }
+        let graph = self.graph;
+        let mut stack = Vec::new();
+        if f(vertex) {
+            stack.push(graph.neighbours_iter(v));
         }
-        self.visited.insert(v);
-        f(self.graph.get_vertex(v).unwrap());
-        if let Some(neighbours) = self.graph.get_neighbours(v) {
-            for nx in neighbours {
-                self.dfs_impl(nx, f);
+        // Explicit stack instead of recursion, so deep graphs do not overflow call stack.
+        while let Some(neighbours) = stack.last_mut() {
+            let Some(nx) = neighbours.next() else {
+                stack.pop();
+                continue;
+            };
+            if self.visited.insert(nx) && f(graph.get_vertex(nx).unwrap()) {
+                stack.push(graph.neighbours_iter(nx));
             }
         }
     }
@@ -79,7 +194,17 @@ impl<'a, T: FromStr + Display> DfsVisitor<'a, T> {
 impl<'a, T: FromStr + Display> GraphVisitor<T> for DfsVisitor<'a, T> {
     fn visit<F: FnMut(&GraphVertex<T>)>(&mut self, vertex: usize, f: F) {
         let mut f = f;
-        self.dfs_impl(vertex, &mut f);
+        self.dfs_impl(vertex, &mut |v| {
+            f(v);
+            true
+        });
+    }
+
+    fn visit_dyn(&mut self, vertex: usize, f: &mut dyn FnMut(&GraphVertex<T>)) {
+        self.dfs_impl(vertex, &mut |v| {
+            f(v);
+            true
+        });
     }
 
     fn clear(&mut self) {
@@ -0,0 +1,130 @@
+use alloc::collections::VecDeque;
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::collections::HashSet;
+use crate::prelude::*;
+use crate::Graph;
+
+/// Helper for visiting edges of graph reachable from start vertex, storing state between runs.
+///
+/// Each edge is passed to callback exactly once, including edges leading to already visited vertices.
+/// Like [`BfsVisitor`](crate::BfsVisitor) and [`DfsVisitor`](crate::DfsVisitor), visitor remembers
+/// visited vertices, so following runs skip edges of vertices visited before.
+///
+/// Order of edges going out of single vertex is order of its neighbours in graph.
+///
+/// ```
+/// use trivial_graph::{EdgeVisitor, Graph};
+/// let mut graph = Graph::new();
+/// for i in 1..=4 {
+///     graph.add_vertex(i, i.to_string());
+/// }
+/// assert!(graph.add_edge(1, 2).is_ok());
+/// assert!(graph.add_edge(2, 3).is_ok());
+/// assert!(graph.add_edge(3, 1).is_ok());
+/// assert!(graph.add_edge(4, 3).is_ok());
+/// let mut edges = Vec::new();
+/// let mut visitor = EdgeVisitor::new(&graph);
+/// visitor.visit_bfs(1, |from, to| edges.push((from, to)));
+/// assert_eq!(edges, vec![(1, 2), (2, 3), (3, 1)]);
+/// visitor.visit_bfs(4, |from, to| edges.push((from, to)));
+/// assert_eq!(edges, vec![(1, 2), (2, 3), (3, 1), (4, 3)]);
+/// ```
+pub struct EdgeVisitor<'a, T: FromStr + Display> {
+    visited: HashSet<usize>,
+    graph: &'a Graph<T>,
+}
+
+impl<'a, T: FromStr + Display> EdgeVisitor<'a, T> {
+    /// Creates new visitor for given graph
+    pub fn new(graph: &'a Graph<T>) -> Self {
+        Self {
+            visited: Default::default(),
+            graph,
+        }
+    }
+
+    /// Visit edges reachable from `start` in `bfs` order: all edges going out of a vertex are visited together,
+    /// vertices go in order of their discovery, so edges of nearer vertices go first.
+    ///
+    /// If `start` not presented in graph or already visited, does nothing.
+    ///
+    /// ```
+    /// use trivial_graph::{EdgeVisitor, Graph};
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(2, 4).is_ok());
+    /// let mut edges = Vec::new();
+    /// EdgeVisitor::new(&graph).visit_bfs(1, |from, to| edges.push((from, to)));
+    /// assert_eq!(edges.len(), 3);
+    /// assert_eq!(edges[2], (2, 4));
+    /// ```
+    pub fn visit_bfs<F: FnMut(usize, usize)>(&mut self, start: usize, mut f: F) {
+        if self.graph.get_vertex(start).is_none() || !self.visited.insert(start) {
+            return;
+        }
+        let mut vertex_queue = VecDeque::from([start]);
+        while let Some(v) = vertex_queue.pop_front() {
+            for nx in self.graph.neighbours_iter(v) {
+                f(v, nx);
+                if self.visited.insert(nx) {
+                    vertex_queue.push_back(nx);
+                }
+            }
+        }
+    }
+
+    /// Visit edges reachable from `start` in `dfs` order: edge is visited when traversal moves along it,
+    /// and if it leads to not visited vertex, edges of that vertex are visited before the remaining edges
+    /// of current one.
+    ///
+    /// If `start` not presented in graph or already visited, does nothing.
+    ///
+    /// ```
+    /// use trivial_graph::{EdgeVisitor, Graph};
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// assert!(graph.add_edge(2, 4).is_ok());
+    /// let mut edges = Vec::new();
+    /// EdgeVisitor::new(&graph).visit_dfs(1, |from, to| edges.push((from, to)));
+    /// assert_eq!(edges.len(), 3);
+    /// let pos = edges.iter().position(|&edge| edge == (1, 2)).unwrap();
+    /// assert_eq!(edges[pos + 1], (2, 4));
+    /// ```
+    pub fn visit_dfs<F: FnMut(usize, usize)>(&mut self, start: usize, mut f: F) {
+        if self.graph.get_vertex(start).is_none() || !self.visited.insert(start) {
+            return;
+        }
+        let mut stack = vec![(start, self.graph.neighbours_iter(start))];
+        while let Some((v, neighbours)) = stack.last_mut() {
+            let v = *v;
+            let Some(nx) = neighbours.next() else {
+                stack.pop();
+                continue;
+            };
+            f(v, nx);
+            if self.visited.insert(nx) {
+                stack.push((nx, self.graph.neighbours_iter(nx)));
+            }
+        }
+    }
+
+    /// Check if vertex was visited by previous runs.
+    pub fn is_visited(&self, vertex: usize) -> bool {
+        self.visited.contains(&vertex)
+    }
+
+    /// Forget visited vertices, so following runs visit all edges again.
+    pub fn clear(&mut self) {
+        self.visited.clear();
+    }
+}
@@ -1,26 +1,193 @@
-use std::fmt::Display;
-use std::str::FromStr;
+use core::fmt::Display;
+use core::str::FromStr;
 
-use crate::{Graph, GraphVertex, VisitOrder};
-use crate::visitors::topological_sort::TopologicalSort;
+use crate::prelude::*;
+use crate::{CycleError, Graph, GraphVertex, VertexNotExistsError, VisitOrder};
 
+/// Traversal of graph which remembers visited vertices between runs.
+///
+/// Trait is object safe, so visitor may be chosen at runtime:
+/// ```
+/// use trivial_graph::{BfsVisitor, DfsVisitor, Graph, GraphVisitor};
+/// let mut graph = Graph::new();
+/// graph.add_vertex(1, "node".to_string());
+/// graph.add_vertex(2, "node2".to_string());
+/// graph.add_vertex(3, "node3".to_string());
+/// assert!(graph.add_edge(1, 2).is_ok());
+/// assert!(graph.add_edge(2, 3).is_ok());
+/// let use_dfs = true;
+/// let mut visitor: Box<dyn GraphVisitor<String>> = if use_dfs {
+///     Box::new(DfsVisitor::new(&graph))
+/// } else {
+///     Box::new(BfsVisitor::new(&graph))
+/// };
+/// let mut visited_vertices = Vec::new();
+/// visitor.visit_dyn(1, &mut |v| visited_vertices.push(v.id));
+/// assert_eq!(visited_vertices, vec![1, 2, 3]);
+/// ```
 pub trait GraphVisitor<T: FromStr + Display> {
-    fn visit<F: FnMut(&GraphVertex<T>)>(&mut self, vertex: usize, f: F);
+    /// Visit vertices reachable from `vertex` which were not visited before and apply `f` to them.
+    ///
+    /// If `vertex` not presented in graph, does nothing, see [`GraphVisitor::try_visit`].
+    fn visit<F: FnMut(&GraphVertex<T>)>(&mut self, vertex: usize, mut f: F)
+    where
+        Self: Sized,
+    {
+        self.visit_dyn(vertex, &mut f);
+    }
+    /// Same as [`GraphVisitor::visit`], but returns error instead of doing nothing
+    /// if `vertex` not presented in graph.
+    ///
+    /// # Errors
+    /// Returns [`VertexNotExistsError`] if start vertex not in graph.
+    ///
+    /// ```
+    /// use trivial_graph::{BfsVisitor, DfsVisitor, Graph, GraphVisitor};
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// let mut visited_vertices = Vec::new();
+    /// let mut visitor = BfsVisitor::new(&graph);
+    /// assert!(visitor.try_visit(1, |v| visited_vertices.push(v.id)).is_ok());
+    /// assert_eq!(visited_vertices, vec![1, 2]);
+    /// let err = DfsVisitor::new(&graph).try_visit(3, |_| {}).unwrap_err();
+    /// assert_eq!(err.vertex(), 3);
+    /// ```
+    fn try_visit<F: FnMut(&GraphVertex<T>)>(
+        &mut self,
+        vertex: usize,
+        f: F,
+    ) -> Result<(), VertexNotExistsError>
+    where
+        Self: Sized,
+    {
+        if self.get_graph().get_vertex(vertex).is_none() {
+            return Err(VertexNotExistsError::new(vertex));
+        }
+        self.visit(vertex, f);
+        Ok(())
+    }
+
+    /// Same as [`GraphVisitor::visit`], but callable on trait objects.
+    fn visit_dyn(&mut self, vertex: usize, f: &mut dyn FnMut(&GraphVertex<T>));
     fn clear(&mut self);
     fn get_graph(&self) -> &Graph<T>;
-    fn visit_all<F: FnMut(&GraphVertex<T>)>(&mut self, visit_order: VisitOrder, mut f: F) {
-        self.clear();
-        let vertices: Vec<_> = match visit_order {
-            VisitOrder::Undefined => self.get_graph().get_vertices_ids().into_iter().collect(),
-            VisitOrder::NumbersAscending => {
-                let mut v: Vec<_> = self.get_graph().get_vertices_ids().into_iter().collect();
-                v.sort_unstable();
-                v
+
+    /// Visit vertices starting from `start` and return ids in order they were visited.
+    ///
+    /// ```
+    /// use trivial_graph::{BfsVisitor, DfsVisitor, Graph, GraphVisitor};
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// graph.add_vertex(3, "node3".to_string());
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// assert_eq!(BfsVisitor::new(&graph).collect_from(1), vec![1, 2, 3]);
+    ///
+    /// let mut visitor = DfsVisitor::new(&graph);
+    /// assert_eq!(visitor.collect_from(2), vec![2, 3]);
+    /// assert_eq!(visitor.collect_from(1), vec![1]);
+    /// ```
+    fn collect_from(&mut self, start: usize) -> Vec<usize> {
+        let mut order = Vec::new();
+        self.visit_dyn(start, &mut |v| order.push(v.id));
+        order
+    }
+
+    /// Visit vertices starting from `start`, threading accumulator through calls of `f`
+    /// like [`Iterator::fold`], and return final accumulator.
+    ///
+    /// ```
+    /// use trivial_graph::{BfsVisitor, DfsVisitor, Graph, GraphVisitor};
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(1, 10);
+    /// graph.add_vertex(2, 20);
+    /// graph.add_vertex(3, 30);
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(2, 3).is_ok());
+    /// let sum = BfsVisitor::new(&graph).fold(2, 0, |acc, v| acc + v.value);
+    /// assert_eq!(sum, 50);
+    /// let values = DfsVisitor::new(&graph).fold(1, Vec::new(), |mut acc, v| {
+    ///     acc.push(v.value);
+    ///     acc
+    /// });
+    /// assert_eq!(values, vec![10, 20, 30]);
+    /// ```
+    fn fold<S, F: FnMut(S, &GraphVertex<T>) -> S>(&mut self, start: usize, init: S, mut f: F) -> S
+    where
+        Self: Sized,
+    {
+        let mut acc = Some(init);
+        self.visit_dyn(start, &mut |v| acc = Some(f(acc.take().unwrap(), v)));
+        acc.unwrap()
+    }
+
+    fn visit_all<F: FnMut(&GraphVertex<T>)>(&mut self, visit_order: VisitOrder, mut f: F)
+    where
+        Self: Sized,
+    {
+        self.visit_all_dyn(visit_order, &mut f);
+    }
+
+    /// Same as [`GraphVisitor::visit_all`], but for [`VisitOrder::TopologicalSort`] checks
+    /// that graph is acyclic before visiting any vertex. For other orders always succeeds.
+    ///
+    /// Topological order is computed once by [`Graph::topological_sort_by`] with vertices ordered
+    /// by ascending ids, the same pass both detects cycle and gives order of starting vertices.
+    ///
+    /// # Errors
+    /// Returns [`CycleError`] if topological order is requested and graph contains cycle.
+    ///
+    /// ```
+    /// use trivial_graph::{BfsVisitor, Graph, GraphVisitor, VisitOrder};
+    /// let mut graph = Graph::new();
+    /// for i in 1..=3 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(2, 1).is_ok());
+    /// assert!(graph.add_edge(3, 2).is_ok());
+    /// let mut visited_vertices = Vec::new();
+    /// let mut visitor = BfsVisitor::new(&graph);
+    /// let res = visitor.try_visit_all(VisitOrder::TopologicalSort, |v| visited_vertices.push(v.id));
+    /// assert!(res.is_ok());
+    /// assert_eq!(visited_vertices, vec![3, 2, 1]);
+    ///
+    /// assert!(graph.add_edge(1, 3).is_ok());
+    /// let mut visited_vertices = Vec::new();
+    /// let mut visitor = BfsVisitor::new(&graph);
+    /// let res = visitor.try_visit_all(VisitOrder::TopologicalSort, |v| visited_vertices.push(v.id));
+    /// assert_eq!(res.unwrap_err().cycle().len(), 3);
+    /// assert!(visited_vertices.is_empty());
+    /// let res = visitor.try_visit_all(VisitOrder::NumbersAscending, |v| visited_vertices.push(v.id));
+    /// assert!(res.is_ok());
+    /// assert_eq!(visited_vertices, vec![1, 3, 2]);
+    /// ```
+    fn try_visit_all<F: FnMut(&GraphVertex<T>)>(
+        &mut self,
+        visit_order: VisitOrder,
+        f: F,
+    ) -> Result<(), CycleError>
+    where
+        Self: Sized,
+    {
+        let visit_order = match visit_order {
+            VisitOrder::TopologicalSort => {
+                VisitOrder::Explicit(self.get_graph().topological_sort_by(|a, b| a.cmp(&b))?)
             }
-            VisitOrder::TopologicalSort => TopologicalSort::new(self.get_graph()).create_order(),
+            visit_order => visit_order,
         };
+        self.visit_all(visit_order, f);
+        Ok(())
+    }
+
+    /// Same as [`GraphVisitor::visit_all`], but callable on trait objects.
+    fn visit_all_dyn(&mut self, visit_order: VisitOrder, f: &mut dyn FnMut(&GraphVertex<T>)) {
+        self.clear();
+        let vertices = visit_order.order_vertices(self.get_graph());
         for v in vertices {
-            self.visit(v, &mut f);
+            self.visit_dyn(v, f);
         }
     }
 }
@@ -1,11 +1,12 @@
 pub use bfs_visitor::BfsVisitor;
 pub use dfs_visitor::DfsVisitor;
+pub use edge_visitor::EdgeVisitor;
 pub use graph_visitor::GraphVisitor;
 pub use visit_order::VisitOrder;
 
 mod bfs_visitor;
 mod dfs_visitor;
+mod edge_visitor;
 mod graph_visitor;
-mod topological_sort;
+pub(crate) mod topological_sort;
 mod visit_order;
-
@@ -1,8 +1,9 @@
-use std::collections::HashSet;
-use std::fmt::Display;
-use std::str::FromStr;
+use core::fmt::Display;
+use core::str::FromStr;
 
+use crate::collections::HashSet;
 use crate::graph::Graph;
+use crate::prelude::*;
 
 pub(crate) struct TopologicalSort<'a, T: FromStr + Display> {
     graph: &'a Graph<T>,
@@ -23,17 +24,33 @@ impl<'a, T: FromStr + Display> TopologicalSort<'a, T> {
             return;
         }
         self.visited.insert(v);
-        if let Some(neighbours) = self.graph.get_neighbours(v) {
-            for nx in neighbours {
-                self.dfs(nx);
+        // Explicit stack instead of recursion, so deep graphs do not overflow call stack.
+        let mut stack = vec![(v, self.sorted_neighbours(v))];
+        while let Some((v, neighbours)) = stack.last_mut() {
+            match neighbours.next() {
+                Some(nx) => {
+                    if self.visited.insert(nx) {
+                        stack.push((nx, self.sorted_neighbours(nx)));
+                    }
+                }
+                None => {
+                    self.order.push(*v);
+                    stack.pop();
+                }
             }
         }
-
-        self.order.push(v);
+    }
+    /// Neighbours in ascending order, so produced order does not depend on hashing.
+    fn sorted_neighbours(&self, v: usize) -> alloc::vec::IntoIter<usize> {
+        let mut neighbours: Vec<_> = self.graph.neighbours_iter(v).collect();
+        neighbours.sort_unstable();
+        neighbours.into_iter()
     }
     pub(crate) fn create_order(mut self) -> Vec<usize> {
-        for v in &self.graph.get_vertices_ids() {
-            self.dfs(*v);
+        let mut roots: Vec<_> = self.graph.get_vertices_ids().into_iter().collect();
+        roots.sort_unstable();
+        for v in roots {
+            self.dfs(v);
         }
         let mut order = self.order;
         order.reverse();
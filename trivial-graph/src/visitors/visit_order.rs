@@ -1,4 +1,20 @@
+use core::cmp::Reverse;
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::prelude::*;
+use crate::Graph;
+
 /// Specifies order of starting vertices for running visitor on all graph
+///
+/// ```
+/// use trivial_graph::VisitOrder;
+/// let order = VisitOrder::Explicit(vec![2, 1]);
+/// assert_eq!(order.clone(), VisitOrder::Explicit(vec![2, 1]));
+/// assert_ne!(order, VisitOrder::NumbersAscending);
+/// assert_eq!(format!("{:?}", VisitOrder::TopologicalSort), "TopologicalSort");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum VisitOrder {
     /// Order not specified, depends on graph and visitor implementation
     Undefined,
@@ -11,5 +27,95 @@ pub enum VisitOrder {
     /// of graph.
     ///
     /// Note: works on acyclic graphs. If cycle exists, order is undefined.
+    ///
+    /// ```
+    /// use trivial_graph::{BfsVisitor, Graph, GraphVisitor, VisitOrder};
+    /// let graph = Graph::path(100_000, |v| v.to_string());
+    /// let mut visited_vertices = Vec::new();
+    /// let mut visitor = BfsVisitor::new(&graph);
+    /// visitor.visit_all(VisitOrder::TopologicalSort, |v| visited_vertices.push(v.id));
+    /// assert_eq!(visited_vertices, (0..100_000).collect::<Vec<_>>());
+    /// ```
     TopologicalSort,
+    /// Order starting vertices by descending of their out-degree,
+    /// vertices with equal degree are ordered by ascending of their numbers.
+    ///
+    /// ```
+    /// use trivial_graph::{DfsVisitor, Graph, GraphVisitor, VisitOrder};
+    /// let mut graph = Graph::new();
+    /// for i in 1..=4 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(1, 2).is_ok());
+    /// assert!(graph.add_edge(3, 1).is_ok());
+    /// assert!(graph.add_edge(3, 4).is_ok());
+    /// let mut visited_vertices = Vec::new();
+    /// let mut visitor = DfsVisitor::new(&graph);
+    /// visitor.visit_all(VisitOrder::DegreeDescending, |v| visited_vertices.push(v.id));
+    /// assert_eq!(visited_vertices[0], 3);
+    /// assert_eq!(visited_vertices.len(), 4);
+    /// ```
+    DegreeDescending,
+    /// Use starting vertices in given order, ids not presented in graph are skipped.
+    ///
+    /// Vertices missing from the list are not used as starting ones,
+    /// so they are visited only if reachable from listed vertices.
+    ///
+    /// ```
+    /// use trivial_graph::{BfsVisitor, Graph, GraphVisitor, VisitOrder};
+    /// let mut graph = Graph::new();
+    /// for i in 1..=5 {
+    ///     graph.add_vertex(i, i.to_string());
+    /// }
+    /// assert!(graph.add_edge(4, 5).is_ok());
+    /// let mut visited_vertices = Vec::new();
+    /// let mut visitor = BfsVisitor::new(&graph);
+    /// let order = VisitOrder::Explicit(vec![3, 10, 4, 1]);
+    /// visitor.visit_all(order, |v| visited_vertices.push(v.id));
+    /// assert_eq!(visited_vertices, vec![3, 4, 5, 1]);
+    /// ```
+    Explicit(Vec<usize>),
+}
+
+impl VisitOrder {
+    /// Get starting vertices of `graph` in this order, as used by
+    /// [`GraphVisitor::visit_all`](crate::GraphVisitor::visit_all).
+    ///
+    /// ```
+    /// use trivial_graph::{Graph, VisitOrder};
+    /// let mut graph = Graph::new();
+    /// graph.add_vertex(3, "node3".to_string());
+    /// graph.add_vertex(1, "node".to_string());
+    /// graph.add_vertex(2, "node2".to_string());
+    /// assert!(graph.add_edge(2, 1).is_ok());
+    /// assert!(graph.add_edge(3, 2).is_ok());
+    /// assert_eq!(VisitOrder::NumbersAscending.order_vertices(&graph), vec![1, 2, 3]);
+    /// assert_eq!(VisitOrder::TopologicalSort.order_vertices(&graph), vec![3, 2, 1]);
+    /// assert_eq!(VisitOrder::Undefined.order_vertices(&graph).len(), 3);
+    /// ```
+    pub fn order_vertices<T: FromStr + Display>(&self, graph: &Graph<T>) -> Vec<usize> {
+        match self {
+            VisitOrder::Undefined => graph.get_vertices_ids().into_iter().collect(),
+            VisitOrder::NumbersAscending => {
+                let mut v: Vec<_> = graph.get_vertices_ids().into_iter().collect();
+                v.sort_unstable();
+                v
+            }
+            VisitOrder::TopologicalSort => graph.topological_order(),
+            VisitOrder::DegreeDescending => {
+                let mut v: Vec<_> = graph
+                    .get_vertices_ids()
+                    .into_iter()
+                    .map(|id| (Reverse(graph.neighbour_count(id).unwrap()), id))
+                    .collect();
+                v.sort_unstable();
+                v.into_iter().map(|(_, id)| id).collect()
+            }
+            VisitOrder::Explicit(order) => order
+                .iter()
+                .copied()
+                .filter(|&id| graph.get_vertex(id).is_some())
+                .collect(),
+        }
+    }
 }